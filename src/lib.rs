@@ -10,6 +10,7 @@ pub use crate::ring::*;
 pub use crate::scalar::*;
 
 mod entry;
+mod util;
 
 pub mod polynomial {
     use crate::monomial::*;
@@ -53,7 +54,6 @@ pub mod polynomial {
         fn total_deg(&self) -> usize {
             self.terms()
                 .keys()
-                .cloned()
                 .map(|a| a.total_deg())
                 .max()
                 .unwrap_or(0)
@@ -106,6 +106,121 @@ pub mod polynomial {
                 .fold(T::zero(), |a, b| a + b)
         }
 
+        /// Value at the point whose coordinate for each variable `v` is `point(v)`.
+        fn eval<F>(&self, point: F) -> Self::Coeff
+        where
+            F: Fn(<Self::Monomial as Monomial>::Var) -> Self::Coeff,
+        {
+            let terms = self.terms();
+            let powers = power_table(terms.keys(), |v| Some(point(v)));
+            terms
+                .into_iter()
+                .map(|(m, c)| {
+                    m.exponents()
+                        .into_iter()
+                        .fold(c.clone(), |a, (v, n)| a * powers[&v][n].clone())
+                })
+                .fold(Self::Coeff::zero(), |a, b| a + b)
+        }
+
+        /// Partial evaluation, substituting `value` for each variable `v` with `point(v) = Some(value)`;
+        /// the result is a polynomial in the remaining variables.
+        fn partial_eval<F>(&self, point: F) -> Self
+        where
+            F: Fn(<Self::Monomial as Monomial>::Var) -> Option<Self::Coeff>,
+        {
+            let terms = self.terms();
+            let powers = power_table(terms.keys(), point);
+            let mut result: BTreeMap<Self::Monomial, Self::Coeff> = BTreeMap::new();
+            for (m, c) in terms {
+                let mut coeff = c.clone();
+                let mut rest = Vec::new();
+                for (v, n) in m.exponents() {
+                    match powers.get(&v) {
+                        Some(ps) => coeff *= ps[n].clone(),
+                        None => rest.push((v, n)),
+                    }
+                }
+                *result
+                    .entry(Self::Monomial::from_exponents(&rest))
+                    .or_insert_with(Self::Coeff::zero) += coeff;
+            }
+            result.retain(|_, c| !c.is_zero());
+            Self::from_terms(result)
+        }
+
+        /// Substitutes the polynomial `subst(v)` for each variable `v`.
+        fn substitute<Q, F>(&self, subst: F) -> Q
+        where
+            Q: Polynomial<Coeff = Self::Coeff>,
+            Scalar<Self::Coeff>: Mul<Q, Output = Q>,
+            F: Fn(<Self::Monomial as Monomial>::Var) -> Q,
+        {
+            let terms = self.terms();
+            let powers = power_table(terms.keys(), |v| Some(subst(v)));
+            terms
+                .into_iter()
+                .map(|(m, c)| {
+                    m.exponents()
+                        .into_iter()
+                        .fold(Scalar(c.clone()) * Q::one(), |a, (v, n)| {
+                            a * powers[&v][n].clone()
+                        })
+                })
+                .fold(Q::zero(), |a, b| a + b)
+        }
+
+        /// Formal partial derivative with respect to `var`.
+        fn derivative(&self, var: <Self::Monomial as Monomial>::Var) -> Self {
+            let x = Self::Monomial::var(var);
+            Self::from_terms(
+                self.terms()
+                    .into_iter()
+                    .filter_map(|(m, c)| {
+                        let n = m.exponent(var);
+                        let c = Self::Coeff::from_nat(n) * c.clone();
+                        if c.is_zero() {
+                            None
+                        } else {
+                            Some(((m / x.clone()).unwrap(), c))
+                        }
+                    })
+                    .collect(),
+            )
+        }
+
+        /// Gcd of the coefficients, zero for the zero polynomial.
+        fn content(&self) -> Self::Coeff
+        where
+            Self::Coeff: GcdDomain,
+        {
+            self.terms()
+                .values()
+                .fold(Self::Coeff::zero(), |c, a| c.gcd_domain(a))
+        }
+
+        /// The polynomial divided by its content.
+        fn primitive_part(&self) -> Self
+        where
+            Self::Coeff: GcdDomain,
+        {
+            let c = self.content();
+            if c.is_zero() {
+                return self.clone();
+            }
+            Self::from_terms(
+                self.terms()
+                    .into_iter()
+                    .map(|(m, a)| (m, a.exact_div(&c).unwrap()))
+                    .collect(),
+            )
+        }
+
+        /// LaTeX rendering of the polynomial, to be used with `format!` and friends.
+        fn latex(&self) -> Latex<&Self> {
+            Latex::new(self)
+        }
+
         fn spol(self, other: Self) -> Self
         where
             Self::Coeff: Field,
@@ -114,8 +229,8 @@ pub mod polynomial {
             let (mx, cx) = mcx.unwrap();
             let (mcy, g) = other.split_lead_term();
             let (my, cy) = mcy.unwrap();
-            let m = mx.lcm(my);
-            let mx: Self::Monomial = (m / mx).unwrap();
+            let m = mx.clone().lcm(my.clone());
+            let mx: Self::Monomial = (m.clone() / mx).unwrap();
             let my: Self::Monomial = (m / my).unwrap();
             let f = Scalar(cx.clone().recip()) * Self::from_monomial(mx) * f;
             let g = Scalar(cy.clone().recip()) * Self::from_monomial(my) * g;
@@ -132,7 +247,7 @@ pub mod polynomial {
             let (md, g) = g.split_lead_term();
             let (d, c) = md.unwrap();
             while let Some((lt_f, lc_f)) = r.pop_lead_term() {
-                match lt_f / d {
+                match lt_f.clone() / d.clone() {
                     None => {
                         r += Scalar(lc_f) * Self::from_monomial(lt_f);
                         break;
@@ -168,7 +283,7 @@ pub mod polynomial {
             while let Some((lt_f, lc_f)) = self.pop_lead_term() {
                 if let Some((i, d, c, g)) = gs
                     .clone()
-                    .filter_map(|(i, ((d, c), g))| (lt_f / d).map(|t| (i, t, c, g)))
+                    .filter_map(|(i, ((d, c), g))| (lt_f.clone() / d).map(|t| (i, t, c, g)))
                     .next()
                 {
                     let k = lc_f / c;
@@ -183,15 +298,78 @@ pub mod polynomial {
         }
     }
 
+    /// Powers `x^0, ..., x^d` of the value `x = value(v)` of each variable `v` given a value,
+    /// up to the degree `d` of `v` in the monomials, so that they are shared among terms.
+    fn power_table<'a, X, T, F>(
+        monomials: impl Iterator<Item = &'a X>,
+        value: F,
+    ) -> BTreeMap<X::Var, Vec<T>>
+    where
+        X: Monomial + 'a,
+        T: Ring,
+        F: Fn(X::Var) -> Option<T>,
+    {
+        let mut degrees: BTreeMap<X::Var, usize> = BTreeMap::new();
+        for m in monomials {
+            for (v, n) in m.exponents() {
+                let d = degrees.entry(v).or_insert(0);
+                *d = (*d).max(n);
+            }
+        }
+        degrees
+            .into_iter()
+            .filter_map(|(v, d)| {
+                let x = value(v)?;
+                let mut powers = vec![T::one()];
+                for _ in 0..d {
+                    let p = powers.last().unwrap().clone() * x.clone();
+                    powers.push(p);
+                }
+                Some((v, powers))
+            })
+            .collect()
+    }
+
+    /// Quotient of `f` by `g`, if `g` divides `f`, by long division on the leading terms.
+    pub(crate) fn exact_quotient<P: Polynomial>(f: &P, g: &P) -> Option<P>
+    where
+        Scalar<P::Coeff>: Mul<P, Output = P>,
+        P::Coeff: IntegralDomain,
+    {
+        let (lm, lc) = g.lead_term()?;
+        let mut q = P::zero();
+        let mut r = f.clone();
+        while let Some((m, c)) = r.lead_term() {
+            let t = Scalar(c.exact_div(lc)?) * P::from_monomial((m / lm.clone())?);
+            r -= t.clone() * g.clone();
+            q += t;
+        }
+        Some(q)
+    }
+
     pub mod unipol;
     pub use self::unipol::*;
 
     pub mod ordpol;
     pub use self::ordpol::*;
 
+    mod gcd;
+
     pub mod groebner;
     pub use self::groebner::*;
 
+    pub mod jacobian;
+    pub use self::jacobian::*;
+
+    pub mod ideal;
+    pub use self::ideal::*;
+
+    pub mod parse;
+    pub use self::parse::*;
+
+    pub mod display;
+    pub use self::display::*;
+
 }
 
 #[cfg(test)]
@@ -235,4 +413,82 @@ mod tests {
             Some((mon_x * mon_x * mon_z * mon_z, &One::one()))
         );
     }
+
+    #[test]
+    fn evaluation_and_substitution() {
+        let x: &Ordpol<Rational, Grevlex3> = &Ordpol::var(grevlex3::X);
+        let y: &Ordpol<Rational, Grevlex3> = &Ordpol::var(grevlex3::Y);
+        let z: &Ordpol<Rational, Grevlex3> = &Ordpol::var(grevlex3::Z);
+        let c = |n: isize| Ordpol::from_int(n);
+        let f = x * x * y - c(3) * y * z * z + z + c(2);
+        let point = |v| match v {
+            grevlex3::X => Rational::from_integer(2),
+            grevlex3::Y => Rational::new(1, 2),
+            _ => Rational::from_integer(-1),
+        };
+        assert_eq!(f.eval(point), Rational::new(3, 2));
+        assert_eq!(
+            Ordpol::<Rational, Grevlex3>::zero().eval(point),
+            Rational::zero()
+        );
+
+        let g = f.partial_eval(|v| match v {
+            grevlex3::Z => Some(Rational::from_integer(-1)),
+            _ => None,
+        });
+        assert_eq!(g, x * x * y - c(3) * y + c(1));
+        assert_eq!(f.partial_eval(|_| None), f);
+        assert_eq!(f.partial_eval(|v| Some(point(v))), c(3) / c(2));
+
+        let h: Ordpol<Rational, Grevlex3> = f.substitute(|v| match v {
+            grevlex3::X => y + z,
+            grevlex3::Y => x.clone(),
+            _ => c(0),
+        });
+        assert_eq!(h, (y + z) * (y + z) * x + c(2));
+
+        let t: &Unipol<Rational> = &Unipol::x();
+        let u: Unipol<Rational> = f.substitute(|v| match v {
+            grevlex3::X => t.clone(),
+            grevlex3::Y => t * t,
+            _ => Unipol::one(),
+        });
+        let three = &Unipol::from_int(3);
+        assert_eq!(u, t.clone().pow(4) - three * t * t + three);
+        assert_eq!(
+            u.eval(|_| Rational::from_integer(2)),
+            Rational::from_integer(7)
+        );
+    }
+
+    monomial_context!(struct Xyz = MonomialDescriptor::new(vec!["x", "y", "z"], MonomialOrder::Grevlex););
+
+    #[test]
+    fn dyn_monomial_groebner() {
+        let x: &Ordpol<Rational, Grevlex3> = &Ordpol::var(grevlex3::X);
+        let y: &Ordpol<Rational, Grevlex3> = &Ordpol::var(grevlex3::Y);
+        let z: &Ordpol<Rational, Grevlex3> = &Ordpol::var(grevlex3::Z);
+        let ideal = vec![x * x * y - z, x * x * x - y * y - x * z];
+
+        let dx: &Ordpol<Rational, DynMonomial<Xyz>> = &Ordpol::var(DynVar(0));
+        let dy: &Ordpol<Rational, DynMonomial<Xyz>> = &Ordpol::var(DynVar(1));
+        let dz: &Ordpol<Rational, DynMonomial<Xyz>> = &Ordpol::var(DynVar(2));
+        let dyn_ideal = vec![dx * dx * dy - dz, dx * dx * dx - dy * dy - dx * dz];
+
+        let lms = |gb: Vec<Ordpol<Rational, Grevlex3>>| -> Vec<Vec<usize>> {
+            gb.iter()
+                .map(|g| g.lead_monom().unwrap().0.to_vec())
+                .collect()
+        };
+        let dyn_lms = |gb: Vec<Ordpol<Rational, DynMonomial<Xyz>>>| -> Vec<Vec<usize>> {
+            gb.into_iter()
+                .map(|g| g.lead_monom().unwrap().into_vec())
+                .collect()
+        };
+        assert_eq!(
+            lms(buchberger(ideal.clone())),
+            dyn_lms(buchberger(dyn_ideal.clone()))
+        );
+        assert_eq!(lms(f5(ideal)), dyn_lms(f5(dyn_ideal)));
+    }
 }
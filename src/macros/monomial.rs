@@ -1,61 +1,29 @@
-macro_rules! lex {
-    ($e:expr) => {
-          |xs: [usize; $e], ys: [usize; $e]| -> ::std::cmp::Ordering {
-              for (i, j) in xs.into_iter().zip(ys.into_iter()) {
-                  match i.cmp(j) {
-                      ::std::cmp::Ordering::Equal => {
-                          continue;
-                      }
-                      ord => {
-                          return ord;
-                      }
-                  }
-              }
-              return ::std::cmp::Ordering::Equal;
-          }
-    };
-}
-
-macro_rules! revlex {
-    ($e:expr) => {
-         |xs: [usize; $e], ys: [usize; $e]| -> ::std::cmp::Ordering {
-              for (i, j) in ys.into_iter().rev().zip(xs.into_iter().rev()) {
-                  match i.cmp(j) {
-                      ::std::cmp::Ordering::Equal => {
-                          continue;
-                      }
-                      ord => {
-                          return ord;
-                      }
-                  }
-              }
-              return ::std::cmp::Ordering::Equal;
-          }
-    };
-}
+/// Declares a monomial type over the given variables, in variable decreasing order.
+/// The ordering is one of `lex!`, `grlex!`, `grevlex!`, a block ordering
+/// `block!(k => grevlex!, l => lex!, ...)` whose sizes add up to the number of variables,
+/// a weighted degree ordering `weighted!([w1, w2, ...] => grevlex!)`,
+/// a matrix ordering `matrix!([a, b, ...], [c, d, ...], ...)` with integer rows
+/// (which must satisfy `order::is_monomial_matrix`, checked on the first comparison),
+/// or any expression comparing two exponent slices.
+/// Block sizes, weights and matrix rows not matching the variables are compile errors.
+///
+/// `new_monomial!(impl Homogenized(module::H) for Monom over Base)` pairs two such types,
+/// where `Monom` has the variables of `Base` and the homogenizing variable `H`.
+macro_rules! new_monomial {
+    (impl Homogenized($h:path) for $monom:ident over $base:ident) => {
+        const _: () = assert!(
+            $monom::VAR_COUNT == $base::VAR_COUNT + 1,
+            concat!(stringify!($monom), " must have one more variable than ", stringify!($base))
+        );
 
-macro_rules! grlex {
-    ($e:expr) => {
-         |xs: [usize; $e], ys: [usize; $e]| -> ::std::cmp::Ordering {
-              let wx : usize = xs.into_iter().sum();
-              let wy : usize = ys.into_iter().sum();
-              wx.cmp(&wy).then(lex!($e)(xs, ys))
-         }
+        impl $crate::monomial::Homogenized for $monom {
+            type Base = $base;
 
+            fn homogenizing_var() -> <$monom as $crate::monomial::Monomial>::Var {
+                $h
+            }
+        }
     };
-}
-
-macro_rules! grevlex {
-    ($e:expr) => {
-          |xs: [usize; $e], ys: [usize; $e]| -> ::std::cmp::Ordering {
-              let wx : usize = xs.into_iter().sum();
-              let wy : usize = ys.into_iter().sum();
-              wx.cmp(&wy).then(revlex!($e)(xs, ys))
-          }
-    };
-}
-
-macro_rules! new_monomial {
     (@impl_monom ($($var:ident)*) ($($vecs:expr);*) ($cmp:expr) $monom:ident $mod:ident) => {
         pub mod $mod {
             use ::num_traits::*;
@@ -134,8 +102,8 @@ macro_rules! new_monomial {
                     let mut arr = [0; $monom::VAR_COUNT];
                     let vec: Vec<_> = self
                         .0
-                        .into_iter()
-                        .zip(other.0.into_iter())
+                        .iter()
+                        .zip(other.0.iter())
                         .map(|(a, b)| a + b)
                         .collect();
                     arr.copy_from_slice(&vec[..$monom::VAR_COUNT]);
@@ -177,8 +145,8 @@ macro_rules! new_monomial {
                     if self.0.iter().zip(other.0.iter()).all(|(i, j)| i >= j) {
                         let mut arr = [0 ; $monom::VAR_COUNT];
                         let vec: Vec<_> =
-                            self.0.into_iter()
-                                .zip(other.0.into_iter())
+                            self.0.iter()
+                                .zip(other.0.iter())
                                 .map(|(i, j)| i - j).collect();
                         arr.copy_from_slice(&vec[..$monom::VAR_COUNT]);
                         Some($monom(arr))
@@ -190,13 +158,19 @@ macro_rules! new_monomial {
 
             impl PartialOrd for $monom {
                 fn partial_cmp(&self, other: &$monom) -> Option<_cmp::Ordering> {
-                    Some($cmp(self.0, other.0))
+                    Some(self.cmp(other))
                 }
             }
 
             impl Ord for $monom {
                 fn cmp(&self, other: &$monom) -> _cmp::Ordering {
-                    $cmp(self.0, other.0)
+                    $cmp(&self.0[..], &other.0[..])
+                }
+            }
+
+            impl ::std::fmt::Display for $monom {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    monomial::write_monomial(f, self, monomial::Notation::Plain)
                 }
             }
 
@@ -213,6 +187,12 @@ macro_rules! new_monomial {
                     }
                 }
 
+                fn var_name(v: Var) -> String {
+                    match v {
+                        $(Var::$var => stringify!($var).to_lowercase(),)*
+                    }
+                }
+
                 fn exponent(&self, v: Var) -> usize {
                     self.0[$monom::calc_index(v)]
                 }
@@ -258,9 +238,115 @@ macro_rules! new_monomial {
     (@count_vars) => { 0usize };
     (@count_vars $v:ident $($rest:tt)*) => {1usize + new_monomial!(@count_vars $($rest)*) };
     (impl Monomial($($var:ident),*; $cmp:ident!) for $monom:ident in $mod:ident) => {
-        new_monomial!{impl Monomial($($var),*; $cmp!($monom::VAR_COUNT))for $monom in $mod}
+        new_monomial!{impl Monomial($($var),*; $crate::monomial::order::$cmp) for $monom in $mod}
+    };
+    (
+        impl Monomial($($var:ident),*; block!($($n:expr => $cmp:ident!),+))
+        for $monom:ident in $mod:ident
+    ) => {
+        new_monomial!{
+            impl Monomial(
+                $($var),*;
+                |xs: &[usize], ys: &[usize]| {
+                    const _: () = assert!(
+                        new_monomial!(@count_vars $($var)*) == 0 $(+ $n)+,
+                        "block sizes must add up to the number of variables"
+                    );
+                    $crate::monomial::order::block(xs, ys, &[
+                        $(($n, $crate::monomial::order::$cmp as $crate::monomial::order::Comparison)),+
+                    ])
+                }
+            ) for $monom in $mod
+        }
+    };
+    (
+        impl Monomial($($var:ident),*; weighted!([$($w:expr),+] => $cmp:ident!))
+        for $monom:ident in $mod:ident
+    ) => {
+        new_monomial!{
+            impl Monomial(
+                $($var),*;
+                |xs: &[usize], ys: &[usize]| {
+                    const _: () = assert!(
+                        [$($w),+].len() == new_monomial!(@count_vars $($var)*),
+                        "there must be one weight per variable"
+                    );
+                    let weights: &[usize] = &[$($w),+];
+                    $crate::monomial::order::weighted(weights, $crate::monomial::order::$cmp, xs, ys)
+                }
+            ) for $monom in $mod
+        }
+    };
+    (
+        impl Monomial($($var:ident),*; matrix!($([$($a:expr),+]),+))
+        for $monom:ident in $mod:ident
+    ) => {
+        new_monomial!{
+            impl Monomial(
+                $($var),*;
+                |xs: &[usize], ys: &[usize]| {
+                    const _: () = {
+                        let arity = new_monomial!(@count_vars $($var)*);
+                        $(assert!([$($a),+].len() == arity, "each row must have one entry per variable");)+
+                    };
+                    static CHECK: ::std::sync::Once = ::std::sync::Once::new();
+                    let rows: &[&[i64]] = &[$(&[$($a),+]),+];
+                    CHECK.call_once(|| assert!(
+                        $crate::monomial::order::is_monomial_matrix(rows, xs.len()),
+                        "not a monomial ordering matrix"
+                    ));
+                    $crate::monomial::order::matrix(rows, xs, ys)
+                }
+            ) for $monom in $mod
+        }
     };
     (impl Monomial($($var:ident),*; $cmp:expr) for $monom:ident in $mod:ident) => {
         new_monomial!{ @impl_with_vars ($($var)*) ($cmp) $monom $mod }
     };
 }
+
+/// Declares a marker type implementing `MonomialContext`, to be used as `DynMonomial<Name>`.
+/// The descriptor is either given up front (`= expr`) and built lazily,
+/// or registered once at runtime with `Name::init(descriptor)`.
+#[macro_export]
+macro_rules! monomial_context {
+    ($(#[$attr:meta])* $vis:vis struct $name:ident = $desc:expr;) => {
+        $crate::monomial_context!(@decl ($(#[$attr])*) $vis $name);
+        impl $crate::monomial::MonomialContext for $name {
+            fn descriptor() -> &'static $crate::monomial::MonomialDescriptor {
+                $name::cell().get_or_init(|| $desc)
+            }
+        }
+    };
+    ($(#[$attr:meta])* $vis:vis struct $name:ident;) => {
+        $crate::monomial_context!(@decl ($(#[$attr])*) $vis $name);
+        impl $crate::monomial::MonomialContext for $name {
+            fn descriptor() -> &'static $crate::monomial::MonomialDescriptor {
+                $name::cell()
+                    .get()
+                    .expect(concat!(stringify!($name), " used before initialisation"))
+            }
+        }
+    };
+    (@decl ($($attr:tt)*) $vis:vis $name:ident) => {
+        $($attr)*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        $vis struct $name;
+
+        impl $name {
+            fn cell() -> &'static ::std::sync::OnceLock<$crate::monomial::MonomialDescriptor> {
+                static CELL: ::std::sync::OnceLock<$crate::monomial::MonomialDescriptor> =
+                    ::std::sync::OnceLock::new();
+                &CELL
+            }
+
+            /// Registers the descriptor; fails if it has already been set.
+            #[allow(dead_code)]
+            pub fn init(
+                desc: $crate::monomial::MonomialDescriptor,
+            ) -> Result<(), $crate::monomial::MonomialDescriptor> {
+                $name::cell().set(desc)
+            }
+        }
+    };
+}
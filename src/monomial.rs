@@ -1,4 +1,5 @@
 use num_traits::*;
+use std::fmt;
 use std::ops::*;
 
 #[cfg(test)]
@@ -9,12 +10,30 @@ use rand::Rng;
 /// Monomial multiplicative monoid, endowed with monoidal ordering.
 /// A type must satisfy the axioms of ordered free commutative monoids;
 /// I.e. a * b = b * a, a * (b * c) = (a * b) * c, 1 <= a, and "a <= b implies a * c <= b * c".
-pub trait Monomial: Div<Self, Output = Option<Self>> + Ord + One + Copy {
+pub trait Monomial: Div<Self, Output = Option<Self>> + Ord + One + Clone {
     type Var: Copy + PartialEq + Eq + Ord;
 
     /// Returns the list of variables, in decreasing order;
     fn variables() -> Vec<Self::Var>;
     fn var(var: Self::Var) -> Self;
+
+    /// Name of the variable, used when parsing and printing polynomials.
+    /// Defaults to `x1`, `x2`, ..., following the order of `variables()`.
+    fn var_name(var: Self::Var) -> String {
+        let i = Self::variables()
+            .into_iter()
+            .position(|v| v == var)
+            .unwrap();
+        format!("x{}", i + 1)
+    }
+
+    /// Looks up the variable with the given name.
+    fn var_named(name: &str) -> Option<Self::Var> {
+        Self::variables()
+            .into_iter()
+            .find(|v| Self::var_name(*v) == name)
+    }
+
     fn from_exponents(exps: &[(Self::Var, usize)]) -> Self {
         exps.iter()
             .map(|(v, i)| pow(Self::var(*v), *i))
@@ -38,7 +57,7 @@ pub trait Monomial: Div<Self, Output = Option<Self>> + Ord + One + Copy {
     }
 
     fn divides(&self, other: &Self) -> bool {
-        (*other / *self).is_some()
+        (other.clone() / self.clone()).is_some()
     }
 
     fn lcm(self, other: Self) -> Self {
@@ -52,6 +71,85 @@ pub trait Monomial: Div<Self, Output = Option<Self>> + Ord + One + Copy {
     }
 }
 
+/// Monomials over the variables of `Base` and an extra homogenizing variable.
+/// The other variables correspond to those of `Base` by their positions in `variables()`.
+///
+/// Declared with `new_monomial!(impl Homogenized(module::H) for Monom over Base)`.
+pub trait Homogenized: Monomial {
+    type Base: Monomial;
+
+    fn homogenizing_var() -> Self::Var;
+
+    /// The monomial, free from the homogenizing variable.
+    fn from_base(m: &Self::Base) -> Self {
+        let exps: Vec<_> = Self::base_variables()
+            .into_iter()
+            .zip(m.exponents())
+            .map(|(v, (_, n))| (v, n))
+            .collect();
+        Self::from_exponents(&exps)
+    }
+
+    /// The monomial with the homogenizing variable set to one.
+    fn to_base(&self) -> Self::Base {
+        let exps: Vec<_> = Self::Base::variables()
+            .into_iter()
+            .zip(Self::base_variables())
+            .map(|(v, w)| (v, self.exponent(w)))
+            .collect();
+        Self::Base::from_exponents(&exps)
+    }
+
+    /// The variables other than the homogenizing one, in decreasing order.
+    fn base_variables() -> Vec<Self::Var> {
+        let h = Self::homogenizing_var();
+        Self::variables().into_iter().filter(|v| *v != h).collect()
+    }
+}
+
+pub mod order;
+pub use self::order::MonomialOrder;
+
+pub mod dynamic;
+pub use self::dynamic::*;
+
+/// Notation used when printing monomials and polynomials.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Notation {
+    Plain,
+    Latex,
+}
+
+/// Writes the monomial as a product of powers, e.g. `x^2*y` (plain) or `x^{2} y` (LaTeX).
+/// The unit monomial is written as `1`.
+pub fn write_monomial<X, W>(f: &mut W, m: &X, notation: Notation) -> fmt::Result
+where
+    X: Monomial,
+    W: fmt::Write,
+{
+    let mut first = true;
+    for (v, n) in m.exponents().into_iter().filter(|(_, n)| *n > 0) {
+        if !first {
+            f.write_str(match notation {
+                Notation::Plain => "*",
+                Notation::Latex => " ",
+            })?;
+        }
+        first = false;
+        f.write_str(&X::var_name(v))?;
+        if n > 1 {
+            match notation {
+                Notation::Plain => write!(f, "^{}", n)?,
+                Notation::Latex => write!(f, "^{{{}}}", n)?,
+            }
+        }
+    }
+    if first {
+        f.write_str("1")?;
+    }
+    Ok(())
+}
+
 #[derive(Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug)]
 pub struct Power(pub usize);
 
@@ -78,7 +176,7 @@ impl One for Power {
     }
 
     fn is_one(&self) -> bool {
-        self.0.is_one()
+        self.0 == 0
     }
 }
 
@@ -93,6 +191,10 @@ impl Monomial for Power {
         Power(1)
     }
 
+    fn var_name(_: ()) -> String {
+        "x".to_string()
+    }
+
     fn exponent(&self, _: ()) -> usize {
         self.0
     }
@@ -102,6 +204,12 @@ impl Monomial for Power {
     }
 }
 
+impl fmt::Display for Power {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_monomial(f, self, Notation::Plain)
+    }
+}
+
 impl Div for Power {
     type Output = Option<Self>;
     #[allow(clippy::suspicious_arithmetic_impl)]
@@ -126,9 +234,29 @@ new_monomial!(impl Monomial(W, X, Y, Z; lex!) for Lex4 in lex4);
 new_monomial!(impl Monomial(W, X, Y, Z; grevlex!) for Grevlex4 in grevlex4);
 new_monomial!(impl Monomial(W, X, Y, Z; grlex!) for Grlex4 in grlex4);
 
+// Homogenizations of grevlex, by a variable smaller than the others.
+new_monomial!(impl Monomial(X, Y, H; grevlex!) for Grevlex2H in grevlex2h);
+new_monomial!(impl Homogenized(grevlex2h::H) for Grevlex2H over Grevlex2);
+
+new_monomial!(impl Monomial(X, Y, Z, H; grevlex!) for Grevlex3H in grevlex3h);
+new_monomial!(impl Homogenized(grevlex3h::H) for Grevlex3H over Grevlex3);
+
+new_monomial!(impl Monomial(W, X, Y, Z, H; grevlex!) for Grevlex4H in grevlex4h);
+new_monomial!(impl Homogenized(grevlex4h::H) for Grevlex4H over Grevlex4);
+
 new_monomial!(impl Monomial(V, W, X, Y, Z; lex!) for Lex5 in lex5);
 new_monomial!(impl Monomial(V, W, X, Y, Z; grevlex!) for Grevlex5 in grevlex5);
 new_monomial!(impl Monomial(V, W, X, Y, Z; grlex!) for Grlex5 in grlex5);
 
+// Elimination orders: grevlex on the first k variables, then grevlex on the rest.
+new_monomial!(impl Monomial(X, Y, Z; block!(1 => grevlex!, 2 => grevlex!)) for Elim1Grevlex3 in elim1grevlex3);
+new_monomial!(impl Monomial(X, Y, Z; block!(2 => grevlex!, 1 => grevlex!)) for Elim2Grevlex3 in elim2grevlex3);
+
+new_monomial!(impl Monomial(W, X, Y, Z; block!(1 => grevlex!, 3 => grevlex!)) for Elim1Grevlex4 in elim1grevlex4);
+new_monomial!(impl Monomial(W, X, Y, Z; block!(2 => grevlex!, 2 => grevlex!)) for Elim2Grevlex4 in elim2grevlex4);
+
+new_monomial!(impl Monomial(V, W, X, Y, Z; block!(1 => grevlex!, 4 => grevlex!)) for Elim1Grevlex5 in elim1grevlex5);
+new_monomial!(impl Monomial(V, W, X, Y, Z; block!(2 => grevlex!, 3 => grevlex!)) for Elim2Grevlex5 in elim2grevlex5);
+
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,278 @@
+use crate::monomial::order::MonomialOrder;
+use crate::monomial::{write_monomial, Monomial, Notation};
+use num_traits::*;
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::ops::*;
+
+#[cfg(test)]
+use quickcheck::*;
+#[cfg(test)]
+use rand::Rng;
+
+/// Variable names and monomial ordering shared by every monomial of a runtime-arity monomial type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MonomialDescriptor {
+    names: Vec<String>,
+    order: MonomialOrder,
+}
+
+impl MonomialDescriptor {
+    /// Variables are given in decreasing order, as in `Monomial::variables()`.
+    pub fn new<S, I>(names: I, order: MonomialOrder) -> MonomialDescriptor
+    where
+        S: Into<String>,
+        I: IntoIterator<Item = S>,
+    {
+        let names: Vec<String> = names.into_iter().map(Into::into).collect();
+        assert!(
+            order.accepts_arity(names.len()),
+            "monomial order does not fit the number of variables"
+        );
+        MonomialDescriptor { names, order }
+    }
+
+    pub fn arity(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    pub fn order(&self) -> &MonomialOrder {
+        &self.order
+    }
+
+    pub fn var_named(&self, name: &str) -> Option<DynVar> {
+        self.names.iter().position(|n| n == name).map(DynVar)
+    }
+}
+
+/// Supplies the descriptor of a `DynMonomial`.
+/// Usually implemented via `monomial_context!`.
+pub trait MonomialContext: 'static {
+    fn descriptor() -> &'static MonomialDescriptor;
+}
+
+/// Variable of a `DynMonomial`, given by its index in the descriptor.
+/// As with the generated `Var` enums, earlier variables are greater.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DynVar(pub usize);
+
+impl PartialOrd for DynVar {
+    fn partial_cmp(&self, other: &DynVar) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DynVar {
+    fn cmp(&self, other: &DynVar) -> Ordering {
+        other.0.cmp(&self.0)
+    }
+}
+
+/// Monomial with the number of variables and ordering determined at runtime by the context `C`.
+pub struct DynMonomial<C> {
+    exps: Vec<usize>,
+    context: PhantomData<fn() -> C>,
+}
+
+impl<C: MonomialContext> DynMonomial<C> {
+    /// Builds a monomial from its exponent vector, which must have one entry per variable.
+    pub fn from_vec(exps: Vec<usize>) -> DynMonomial<C> {
+        assert_eq!(
+            exps.len(),
+            C::descriptor().arity(),
+            "exponent vector length does not match the monomial context"
+        );
+        DynMonomial {
+            exps,
+            context: PhantomData,
+        }
+    }
+
+    pub fn exps(&self) -> &[usize] {
+        &self.exps
+    }
+
+    pub fn into_vec(self) -> Vec<usize> {
+        self.exps
+    }
+
+    fn zip_with<F: Fn(usize, usize) -> usize>(&self, other: &Self, f: F) -> Self {
+        DynMonomial {
+            exps: self
+                .exps
+                .iter()
+                .zip(other.exps.iter())
+                .map(|(a, b)| f(*a, *b))
+                .collect(),
+            context: PhantomData,
+        }
+    }
+}
+
+impl<C> Clone for DynMonomial<C> {
+    fn clone(&self) -> Self {
+        DynMonomial {
+            exps: self.exps.clone(),
+            context: PhantomData,
+        }
+    }
+}
+
+impl<C> fmt::Debug for DynMonomial<C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("DynMonomial").field(&self.exps).finish()
+    }
+}
+
+impl<C: MonomialContext> fmt::Display for DynMonomial<C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_monomial(f, self, Notation::Plain)
+    }
+}
+
+impl<C> PartialEq for DynMonomial<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.exps == other.exps
+    }
+}
+
+impl<C> Eq for DynMonomial<C> {}
+
+impl<C> Hash for DynMonomial<C> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.exps.hash(state)
+    }
+}
+
+impl<C: MonomialContext> PartialOrd for DynMonomial<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C: MonomialContext> Ord for DynMonomial<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        C::descriptor().order().compare(&self.exps, &other.exps)
+    }
+}
+
+impl<C: MonomialContext> Mul for DynMonomial<C> {
+    type Output = DynMonomial<C>;
+    fn mul(self, other: DynMonomial<C>) -> DynMonomial<C> {
+        self.zip_with(&other, Add::add)
+    }
+}
+
+impl<C: MonomialContext> Mul<&DynMonomial<C>> for &DynMonomial<C> {
+    type Output = DynMonomial<C>;
+    fn mul(self, other: &DynMonomial<C>) -> DynMonomial<C> {
+        self.zip_with(other, Add::add)
+    }
+}
+
+impl<C: MonomialContext> Mul<DynMonomial<C>> for &DynMonomial<C> {
+    type Output = DynMonomial<C>;
+    fn mul(self, other: DynMonomial<C>) -> DynMonomial<C> {
+        self.zip_with(&other, Add::add)
+    }
+}
+
+impl<C: MonomialContext> Mul<&DynMonomial<C>> for DynMonomial<C> {
+    type Output = DynMonomial<C>;
+    fn mul(self, other: &DynMonomial<C>) -> DynMonomial<C> {
+        self.zip_with(other, Add::add)
+    }
+}
+
+impl<C: MonomialContext> One for DynMonomial<C> {
+    fn one() -> DynMonomial<C> {
+        DynMonomial {
+            exps: vec![0; C::descriptor().arity()],
+            context: PhantomData,
+        }
+    }
+
+    fn is_one(&self) -> bool {
+        self.exps.iter().all(Zero::is_zero)
+    }
+}
+
+impl<C: MonomialContext> Div for DynMonomial<C> {
+    type Output = Option<DynMonomial<C>>;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, other: DynMonomial<C>) -> Option<DynMonomial<C>> {
+        if other.divides(&self) {
+            Some(self.zip_with(&other, Sub::sub))
+        } else {
+            None
+        }
+    }
+}
+
+impl<C: MonomialContext> Monomial for DynMonomial<C> {
+    type Var = DynVar;
+
+    fn variables() -> Vec<DynVar> {
+        (0..C::descriptor().arity()).map(DynVar).collect()
+    }
+
+    fn var(v: DynVar) -> Self {
+        let mut exps = vec![0; C::descriptor().arity()];
+        exps[v.0] = 1;
+        DynMonomial {
+            exps,
+            context: PhantomData,
+        }
+    }
+
+    fn var_name(v: DynVar) -> String {
+        C::descriptor().names()[v.0].clone()
+    }
+
+    fn var_named(name: &str) -> Option<DynVar> {
+        C::descriptor().var_named(name)
+    }
+
+    fn exponent(&self, v: DynVar) -> usize {
+        self.exps[v.0]
+    }
+
+    fn exponents(&self) -> Vec<(DynVar, usize)> {
+        self.exps
+            .iter()
+            .cloned()
+            .enumerate()
+            .map(|(i, n)| (DynVar(i), n))
+            .collect()
+    }
+
+    fn total_deg(&self) -> usize {
+        self.exps.iter().sum()
+    }
+
+    fn divides(&self, other: &Self) -> bool {
+        self.exps.iter().zip(other.exps.iter()).all(|(i, j)| i <= j)
+    }
+
+    fn lcm(self, other: Self) -> Self {
+        self.zip_with(&other, Ord::max)
+    }
+}
+
+#[cfg(test)]
+impl<C: MonomialContext> Arbitrary for DynMonomial<C> {
+    fn arbitrary<G: Gen>(g: &mut G) -> DynMonomial<C> {
+        let n = C::descriptor().arity();
+        DynMonomial::from_vec(
+            (0..n)
+                .map(|_| g.gen_range(0, usize::MAX / ((1 + n) * 2)))
+                .collect(),
+        )
+    }
+}
@@ -0,0 +1,198 @@
+//! Monomial orderings on exponent vectors, shared by the `new_monomial!` types and `DynMonomial`.
+//! Exponents are given in variable decreasing order.
+
+use num_rational::Ratio;
+use num_traits::*;
+use std::cmp::Ordering;
+
+/// Comparison function on exponent vectors of the same length.
+pub type Comparison = fn(&[usize], &[usize]) -> Ordering;
+
+pub fn lex(xs: &[usize], ys: &[usize]) -> Ordering {
+    xs.iter()
+        .zip(ys.iter())
+        .map(|(i, j)| i.cmp(j))
+        .find(|o| *o != Ordering::Equal)
+        .unwrap_or(Ordering::Equal)
+}
+
+pub fn revlex(xs: &[usize], ys: &[usize]) -> Ordering {
+    ys.iter()
+        .rev()
+        .zip(xs.iter().rev())
+        .map(|(i, j)| i.cmp(j))
+        .find(|o| *o != Ordering::Equal)
+        .unwrap_or(Ordering::Equal)
+}
+
+pub fn grlex(xs: &[usize], ys: &[usize]) -> Ordering {
+    total(xs).cmp(&total(ys)).then_with(|| lex(xs, ys))
+}
+
+pub fn grevlex(xs: &[usize], ys: &[usize]) -> Ordering {
+    total(xs).cmp(&total(ys)).then_with(|| revlex(xs, ys))
+}
+
+/// Block (product) ordering: the first block of variables is compared first,
+/// and the next block is only consulted on a tie.
+pub fn block(xs: &[usize], ys: &[usize], blocks: &[(usize, Comparison)]) -> Ordering {
+    let mut start = 0;
+    for (n, cmp) in blocks {
+        let end = start + n;
+        match cmp(&xs[start..end], &ys[start..end]) {
+            Ordering::Equal => start = end,
+            ord => return ord,
+        }
+    }
+    Ordering::Equal
+}
+
+/// Weighted degree ordering: monomials are compared by `sum w_i * a_i` first,
+/// and ties are broken by `tie`. Weights are given in variable decreasing order.
+pub fn weighted(weights: &[usize], tie: Comparison, xs: &[usize], ys: &[usize]) -> Ordering {
+    let deg = |zs: &[usize]| -> u128 {
+        weights
+            .iter()
+            .zip(zs.iter())
+            .map(|(w, a)| *w as u128 * *a as u128)
+            .sum()
+    };
+    deg(xs).cmp(&deg(ys)).then_with(|| tie(xs, ys))
+}
+
+/// Matrix ordering: monomials are compared lexicographically by the images
+/// of their exponent vectors under the integer matrix `rows`.
+/// See `is_monomial_matrix` for the matrices defining a monomial ordering.
+pub fn matrix<R: AsRef<[i64]>>(rows: &[R], xs: &[usize], ys: &[usize]) -> Ordering {
+    let dot = |row: &[i64], zs: &[usize]| -> i128 {
+        row.iter()
+            .zip(zs.iter())
+            .map(|(w, a)| *w as i128 * *a as i128)
+            .sum()
+    };
+    rows.iter()
+        .map(|row| dot(row.as_ref(), xs).cmp(&dot(row.as_ref(), ys)))
+        .find(|o| *o != Ordering::Equal)
+        .unwrap_or(Ordering::Equal)
+}
+
+/// Checks that `rows` defines a monomial ordering on `arity` variables:
+/// every row has `arity` entries, the matrix has full column rank,
+/// and the first nonzero entry of each column is positive.
+pub fn is_monomial_matrix<R: AsRef<[i64]>>(rows: &[R], arity: usize) -> bool {
+    if rows.iter().any(|row| row.as_ref().len() != arity) {
+        return false;
+    }
+    let positive = (0..arity).all(|j| {
+        rows.iter()
+            .map(|row| row.as_ref()[j])
+            .find(|a| *a != 0)
+            .is_some_and(|a| a > 0)
+    });
+    positive && rank(rows) == arity
+}
+
+fn rank<R: AsRef<[i64]>>(rows: &[R]) -> usize {
+    let mut mat: Vec<Vec<Ratio<i128>>> = rows
+        .iter()
+        .map(|row| {
+            row.as_ref()
+                .iter()
+                .map(|a| Ratio::from(*a as i128))
+                .collect()
+        })
+        .collect();
+    let width = mat.first().map_or(0, Vec::len);
+    let mut rank = 0;
+    for j in 0..width {
+        let pivot = match (rank..mat.len()).find(|&i| !mat[i][j].is_zero()) {
+            Some(i) => i,
+            None => continue,
+        };
+        mat.swap(rank, pivot);
+        let (top, rest) = mat.split_at_mut(rank + 1);
+        let pivot_row = &top[rank];
+        for row in rest {
+            let c = row[j] / pivot_row[j];
+            for (a, b) in row[j..].iter_mut().zip(pivot_row[j..].iter()) {
+                *a -= c * b;
+            }
+        }
+        rank += 1;
+    }
+    rank
+}
+
+fn total(xs: &[usize]) -> usize {
+    xs.iter().sum()
+}
+
+/// Monomial orderings selectable at runtime.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MonomialOrder {
+    Lex,
+    Grlex,
+    Grevlex,
+    /// Product of orderings on consecutive blocks of variables, given with their sizes.
+    Block(Vec<(usize, MonomialOrder)>),
+    /// Weighted degree with the given weights, ties broken by the inner ordering.
+    Weighted(Vec<usize>, Box<MonomialOrder>),
+    /// Matrix ordering given by its rows; see `is_monomial_matrix`.
+    Matrix(Vec<Vec<i64>>),
+}
+
+impl MonomialOrder {
+    /// Block order eliminating the first `k` variables: grevlex on them, then grevlex on the rest.
+    /// Panics if `k > arity`.
+    pub fn elimination(k: usize, arity: usize) -> MonomialOrder {
+        assert!(
+            k <= arity,
+            "cannot eliminate {} out of {} variables",
+            k,
+            arity
+        );
+        MonomialOrder::Block(vec![
+            (k, MonomialOrder::Grevlex),
+            (arity - k, MonomialOrder::Grevlex),
+        ])
+    }
+
+    /// Checks that the ordering is meaningful on `arity` variables.
+    pub fn accepts_arity(&self, arity: usize) -> bool {
+        match self {
+            MonomialOrder::Block(blocks) => {
+                blocks.iter().map(|(n, _)| n).sum::<usize>() == arity
+                    && blocks.iter().all(|(n, o)| o.accepts_arity(*n))
+            }
+            MonomialOrder::Weighted(weights, tie) => {
+                weights.len() == arity && tie.accepts_arity(arity)
+            }
+            MonomialOrder::Matrix(rows) => is_monomial_matrix(rows, arity),
+            _ => true,
+        }
+    }
+
+    /// Compares two exponent vectors of the same length w.r.t. the ordering.
+    pub fn compare(&self, xs: &[usize], ys: &[usize]) -> Ordering {
+        match self {
+            MonomialOrder::Lex => lex(xs, ys),
+            MonomialOrder::Grlex => grlex(xs, ys),
+            MonomialOrder::Grevlex => grevlex(xs, ys),
+            MonomialOrder::Block(blocks) => {
+                let mut start = 0;
+                for (n, ord) in blocks {
+                    let end = start + n;
+                    match ord.compare(&xs[start..end], &ys[start..end]) {
+                        Ordering::Equal => start = end,
+                        ord => return ord,
+                    }
+                }
+                Ordering::Equal
+            }
+            MonomialOrder::Weighted(weights, tie) => {
+                weighted(weights, |_, _| Ordering::Equal, xs, ys).then_with(|| tie.compare(xs, ys))
+            }
+            MonomialOrder::Matrix(rows) => matrix(rows, xs, ys),
+        }
+    }
+}
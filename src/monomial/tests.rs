@@ -4,18 +4,18 @@ use std::iter;
 
 fn mul_resp_ord<X: Monomial>(a: X, b: X, c: X) -> bool {
     match a.cmp(&b) {
-        Equal => a * c == b * c,
-        Less => a * c < b * c,
-        Greater => a * c > b * c,
+        Equal => a * c.clone() == b * c,
+        Less => a * c.clone() < b * c,
+        Greater => a * c.clone() > b * c,
     }
 }
 
 fn one_is_unit<X: Monomial>(xs: X) -> bool {
-    xs * X::one() == xs && xs == X::one() * xs
+    xs.clone() * X::one() == xs && xs == X::one() * xs.clone()
 }
 
 fn mul_assoc<X: Monomial>(xs: X, ys: X, zs: X) -> bool {
-    xs * (ys * zs) == (xs * ys) * zs
+    xs.clone() * (ys.clone() * zs.clone()) == (xs * ys) * zs
 }
 
 fn positive<X: Monomial>(a: X) -> bool {
@@ -23,12 +23,12 @@ fn positive<X: Monomial>(a: X) -> bool {
 }
 
 fn lcm_divisible<X: Monomial>(xs: X, ys: X) -> bool {
-    let lcm = xs.lcm(ys);
+    let lcm = xs.clone().lcm(ys.clone());
     xs.divides(&lcm) && ys.divides(&lcm)
 }
 
 fn lcm_least<X: Monomial>(xs: X, ys: X) -> bool {
-    let lcm = xs.lcm(ys).exponents();
+    let lcm = xs.clone().lcm(ys.clone()).exponents();
     (0..lcm.len()).all(|i| {
         let mut decreased = lcm.clone();
         decreased[i].1 -= 1;
@@ -55,6 +55,128 @@ fn resp_total_deg<X: Monomial>(xs: X, ys: X) -> TestResult {
     }
 }
 
+monomial_context!(struct Lex8Context = MonomialDescriptor::new(
+    vec!["a", "b", "c", "d", "e", "f", "g", "h"],
+    MonomialOrder::Lex
+););
+monomial_context!(struct Grevlex8Context = MonomialDescriptor::new(
+    vec!["a", "b", "c", "d", "e", "f", "g", "h"],
+    MonomialOrder::Grevlex
+););
+monomial_context!(struct Grlex8Context = MonomialDescriptor::new(
+    vec!["a", "b", "c", "d", "e", "f", "g", "h"],
+    MonomialOrder::Grlex
+););
+monomial_context!(struct Block8Context = MonomialDescriptor::new(
+    vec!["a", "b", "c", "d", "e", "f", "g", "h"],
+    MonomialOrder::Block(vec![
+        (3, MonomialOrder::Grevlex),
+        (2, MonomialOrder::Lex),
+        (3, MonomialOrder::Grlex),
+    ])
+););
+monomial_context!(struct Weighted8Context = MonomialDescriptor::new(
+    vec!["a", "b", "c", "d", "e", "f", "g", "h"],
+    MonomialOrder::Weighted(vec![1, 2, 3, 4, 4, 3, 2, 1], Box::new(MonomialOrder::Lex))
+););
+monomial_context!(struct Matrix4Context = MonomialDescriptor::new(
+    vec!["w", "x", "y", "z"],
+    MonomialOrder::Matrix(vec![
+        vec![1, 2, 0, 1],
+        vec![0, 0, 1, -1],
+        vec![3, 1, 0, 0],
+        vec![0, 0, 0, 1],
+    ])
+););
+type DynBlock8 = DynMonomial<Block8Context>;
+type DynWeighted8 = DynMonomial<Weighted8Context>;
+type DynMatrix4 = DynMonomial<Matrix4Context>;
+type DynLex8 = DynMonomial<Lex8Context>;
+type DynGrevlex8 = DynMonomial<Grevlex8Context>;
+type DynGrlex8 = DynMonomial<Grlex8Context>;
+
+new_monomial!(impl Monomial(X, Y, Z; weighted!([1, 2, 3] => grevlex!)) for Weighted3 in weighted3);
+new_monomial!(impl Monomial(X, Y, Z; matrix!([1, 1, 1], [0, 0, -1], [0, -1, 0])) for Matrix3 in matrix3);
+new_monomial!(impl Monomial(X, Y; matrix!([1, -1], [0, 1])) for NotMatrix2 in not_matrix2);
+
+fn eliminates<X: Monomial>(k: usize, xs: X, ys: X) -> TestResult {
+    let vars = X::variables();
+    let has_elim = |m: &X| vars[..k].iter().any(|v| m.exponent(*v) > 0);
+    match (has_elim(&xs), has_elim(&ys)) {
+        (true, false) => TestResult::from_bool(xs > ys),
+        (false, true) => TestResult::from_bool(xs < ys),
+        _ => TestResult::discard(),
+    }
+}
+
+quickcheck! {
+    fn elim1grevlex3_eliminates(xs: Elim1Grevlex3, ys: Elim1Grevlex3) -> TestResult {
+        eliminates(1, xs, ys)
+    }
+
+    fn elim2grevlex5_eliminates(xs: Elim2Grevlex5, ys: Elim2Grevlex5) -> TestResult {
+        eliminates(2, xs, ys)
+    }
+
+    fn dyn_block8_eliminates(xs: DynBlock8, ys: DynBlock8) -> TestResult {
+        eliminates(3, xs, ys)
+    }
+
+    fn elimination_order_grevlex_blocks(xs: Elim2Grevlex4, ys: Elim2Grevlex4) -> bool {
+        let (a, b) = (xs.0, ys.0);
+        let first = Grevlex4([a[0], a[1], 0, 0]).cmp(&Grevlex4([b[0], b[1], 0, 0]));
+        let second = Grevlex4([0, 0, a[2], a[3]]).cmp(&Grevlex4([0, 0, b[2], b[3]]));
+        xs.cmp(&ys) == first.then(second)
+    }
+
+    fn grevlex_as_matrix(xs: Grevlex3, ys: Grevlex3) -> bool {
+        xs.cmp(&ys) == Matrix3(xs.0).cmp(&Matrix3(ys.0))
+    }
+
+    fn weighted_resp_weighted_deg(xs: Weighted3, ys: Weighted3) -> TestResult {
+        let deg = |m: &Weighted3| m.0[0] as u128 + 2 * m.0[1] as u128 + 3 * m.0[2] as u128;
+        match deg(&xs).cmp(&deg(&ys)) {
+            Equal => TestResult::from_bool(xs.cmp(&ys) == Grevlex3(xs.0).cmp(&Grevlex3(ys.0))),
+            Less => TestResult::from_bool(xs < ys),
+            Greater => TestResult::from_bool(xs > ys),
+        }
+    }
+
+    fn unit_weights_are_grlex(xs: Lex3, ys: Lex3) -> bool {
+        let order = MonomialOrder::Weighted(vec![1, 1, 1], Box::new(MonomialOrder::Lex));
+        order.compare(&xs.0, &ys.0) == Grlex3(xs.0).cmp(&Grlex3(ys.0))
+    }
+}
+
+#[test]
+fn monomial_matrices() {
+    assert!(order::is_monomial_matrix(&[[1, 0], [0, 1]], 2));
+    assert!(order::is_monomial_matrix(
+        &[[1, 1, 1], [0, 0, -1], [0, -1, 0]],
+        3
+    ));
+    assert!(order::is_monomial_matrix(&[[1, 1], [1, 1], [0, 1]], 2));
+    assert!(!order::is_monomial_matrix(&[[1, 1], [2, 2]], 2));
+    assert!(!order::is_monomial_matrix(&[[1, -1], [0, 1]], 2));
+    assert!(!order::is_monomial_matrix(&[[1, 0, 0], [0, 1, 0]], 3));
+    assert!(!order::is_monomial_matrix(&[[1, 0]], 3));
+}
+
+#[test]
+#[should_panic(expected = "not a monomial ordering matrix")]
+fn macro_rejects_invalid_matrix() {
+    let _ = NotMatrix2([1, 0]).cmp(&NotMatrix2([0, 1]));
+}
+
+#[test]
+#[should_panic]
+fn descriptor_rejects_invalid_matrix() {
+    MonomialDescriptor::new(
+        vec!["x", "y"],
+        MonomialOrder::Matrix(vec![vec![1, 1], vec![-1, -1]]),
+    );
+}
+
 macro_rules! check_monom_prop {
     (@build_quick_check
          $monom:ident
@@ -261,4 +383,114 @@ check_monom! {
         fn lcm_divisible(xs, ys) -> bool;
         fn lcm_least(xs, ys) -> bool;
     }
+
+    mod dyn_lex8 = DynLex8 for {
+        fn mul_resp_ord(xs,ys,zs) -> bool;
+        fn positive(xs) -> bool;
+        fn var_decreasing() -> bool;
+        fn one_is_unit(xs) -> bool;
+        fn mul_assoc(xs, ys, zs) -> bool;
+        fn lcm_divisible(xs, ys) -> bool;
+        fn lcm_least(xs, ys) -> bool;
+    }
+
+    mod dyn_grevlex8 = DynGrevlex8 for {
+        fn mul_resp_ord(xs,ys,zs) -> bool;
+        fn positive(xs) -> bool;
+        fn var_decreasing() -> bool;
+        fn resp_total_deg(xs, ys) -> TestResult;
+        fn one_is_unit(xs) -> bool;
+        fn mul_assoc(xs, ys, zs) -> bool;
+        fn lcm_divisible(xs, ys) -> bool;
+        fn lcm_least(xs, ys) -> bool;
+    }
+
+    mod dyn_grlex8 = DynGrlex8 for {
+        fn mul_resp_ord(xs,ys,zs) -> bool;
+        fn positive(xs) -> bool;
+        fn var_decreasing() -> bool;
+        fn resp_total_deg(xs, ys) -> TestResult;
+        fn one_is_unit(xs) -> bool;
+        fn mul_assoc(xs, ys, zs) -> bool;
+        fn lcm_divisible(xs, ys) -> bool;
+        fn lcm_least(xs, ys) -> bool;
+    }
+
+    mod elim1grevlex3 = Elim1Grevlex3 for {
+        fn mul_resp_ord(xs,ys,zs) -> bool;
+        fn positive(xs) -> bool;
+        fn var_decreasing() -> bool;
+        fn one_is_unit(xs) -> bool;
+        fn mul_assoc(xs, ys, zs) -> bool;
+        fn lcm_divisible(xs, ys) -> bool;
+        fn lcm_least(xs, ys) -> bool;
+    }
+
+    mod elim2grevlex4 = Elim2Grevlex4 for {
+        fn mul_resp_ord(xs,ys,zs) -> bool;
+        fn positive(xs) -> bool;
+        fn var_decreasing() -> bool;
+        fn one_is_unit(xs) -> bool;
+        fn mul_assoc(xs, ys, zs) -> bool;
+        fn lcm_divisible(xs, ys) -> bool;
+        fn lcm_least(xs, ys) -> bool;
+    }
+
+    mod elim2grevlex5 = Elim2Grevlex5 for {
+        fn mul_resp_ord(xs,ys,zs) -> bool;
+        fn positive(xs) -> bool;
+        fn var_decreasing() -> bool;
+        fn one_is_unit(xs) -> bool;
+        fn mul_assoc(xs, ys, zs) -> bool;
+        fn lcm_divisible(xs, ys) -> bool;
+        fn lcm_least(xs, ys) -> bool;
+    }
+
+    mod dyn_block8 = DynBlock8 for {
+        fn mul_resp_ord(xs,ys,zs) -> bool;
+        fn positive(xs) -> bool;
+        fn var_decreasing() -> bool;
+        fn one_is_unit(xs) -> bool;
+        fn mul_assoc(xs, ys, zs) -> bool;
+        fn lcm_divisible(xs, ys) -> bool;
+        fn lcm_least(xs, ys) -> bool;
+    }
+
+    mod weighted3_laws = Weighted3 for {
+        fn mul_resp_ord(xs,ys,zs) -> bool;
+        fn positive(xs) -> bool;
+        fn one_is_unit(xs) -> bool;
+        fn mul_assoc(xs, ys, zs) -> bool;
+        fn lcm_divisible(xs, ys) -> bool;
+        fn lcm_least(xs, ys) -> bool;
+    }
+
+    mod matrix3_laws = Matrix3 for {
+        fn mul_resp_ord(xs,ys,zs) -> bool;
+        fn positive(xs) -> bool;
+        fn var_decreasing() -> bool;
+        fn resp_total_deg(xs, ys) -> TestResult;
+        fn one_is_unit(xs) -> bool;
+        fn mul_assoc(xs, ys, zs) -> bool;
+        fn lcm_divisible(xs, ys) -> bool;
+        fn lcm_least(xs, ys) -> bool;
+    }
+
+    mod dyn_weighted8 = DynWeighted8 for {
+        fn mul_resp_ord(xs,ys,zs) -> bool;
+        fn positive(xs) -> bool;
+        fn one_is_unit(xs) -> bool;
+        fn mul_assoc(xs, ys, zs) -> bool;
+        fn lcm_divisible(xs, ys) -> bool;
+        fn lcm_least(xs, ys) -> bool;
+    }
+
+    mod dyn_matrix4 = DynMatrix4 for {
+        fn mul_resp_ord(xs,ys,zs) -> bool;
+        fn positive(xs) -> bool;
+        fn one_is_unit(xs) -> bool;
+        fn mul_assoc(xs, ys, zs) -> bool;
+        fn lcm_divisible(xs, ys) -> bool;
+        fn lcm_least(xs, ys) -> bool;
+    }
 }
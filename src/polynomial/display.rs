@@ -0,0 +1,264 @@
+use crate::monomial::*;
+use crate::polynomial::*;
+use crate::ring::checked::Checked;
+use crate::ring::modular::Fp;
+use num::bigint::BigInt;
+use num_integer::Integer;
+use num_rational::Ratio;
+use std::fmt;
+
+/// Coefficients as written inside a polynomial.
+///
+/// The default writes the coefficient via `Display` in either notation;
+/// a coefficient written with a leading minus sign is treated as negative unless compound.
+pub trait DisplayCoeff: fmt::Display {
+    /// Whether the coefficient is a sum, and so must be parenthesised as a factor.
+    fn is_compound(&self) -> bool {
+        false
+    }
+
+    /// Writes the coefficient in the given notation.
+    fn write_coeff<W: fmt::Write>(&self, f: &mut W, _notation: Notation) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+impl DisplayCoeff for isize {}
+impl DisplayCoeff for i64 {}
+impl DisplayCoeff for i32 {}
+impl DisplayCoeff for BigInt {}
+impl<M> DisplayCoeff for Fp<M> {}
+impl<I: fmt::Display> DisplayCoeff for Checked<I> {}
+
+impl<I: Clone + Integer + fmt::Display> DisplayCoeff for Ratio<I> {
+    fn write_coeff<W: fmt::Write>(&self, f: &mut W, notation: Notation) -> fmt::Result {
+        match notation {
+            Notation::Latex if !self.is_integer() => {
+                let (n, d) = (self.numer(), self.denom());
+                if *n < I::zero() {
+                    write!(f, "-\\frac{{{}}}{{{}}}", I::zero() - n.clone(), d)
+                } else {
+                    write!(f, "\\frac{{{}}}{{{}}}", n, d)
+                }
+            }
+            _ => write!(f, "{}", self),
+        }
+    }
+}
+
+impl<R: Ring + DisplayCoeff, X: Monomial> DisplayCoeff for Ordpol<R, X> {
+    fn is_compound(&self) -> bool {
+        self.terms().len() > 1
+    }
+
+    fn write_coeff<W: fmt::Write>(&self, f: &mut W, notation: Notation) -> fmt::Result {
+        write_polynomial(f, self, notation)
+    }
+}
+
+impl<R: Ring + DisplayCoeff> DisplayCoeff for Unipol<R> {
+    fn is_compound(&self) -> bool {
+        self.iter().count() > 1
+    }
+
+    fn write_coeff<W: fmt::Write>(&self, f: &mut W, notation: Notation) -> fmt::Result {
+        write_polynomial(f, self, notation)
+    }
+}
+
+/// Writes the polynomial in decreasing term order, e.g. `3/2*x^2*y - z + 1`.
+/// Coefficients are written via `DisplayCoeff` in the same notation,
+/// and compound ones are parenthesised.
+pub fn write_polynomial<P, W>(f: &mut W, p: &P, notation: Notation) -> fmt::Result
+where
+    P: Polynomial,
+    P::Coeff: DisplayCoeff,
+    Scalar<P::Coeff>: Mul<P, Output = P>,
+    W: fmt::Write,
+{
+    let mut first = true;
+    for (m, c) in p.terms().into_iter().rev().filter(|(_, c)| !c.is_zero()) {
+        let compound = c.is_compound();
+        let mut s = String::new();
+        c.write_coeff(&mut s, notation)?;
+        let (negative, body) = match s.strip_prefix('-') {
+            Some(rest) if !compound => (true, rest.trim_start()),
+            _ => (false, &s[..]),
+        };
+        f.write_str(match (first, negative) {
+            (true, false) => "",
+            (true, true) => "-",
+            (false, false) => " + ",
+            (false, true) => " - ",
+        })?;
+        first = false;
+
+        if m.is_one() || body != "1" {
+            match notation {
+                Notation::Plain if compound => write!(f, "({})", body)?,
+                Notation::Latex if compound => write!(f, "\\left({}\\right)", body)?,
+                _ => f.write_str(body)?,
+            }
+            if m.is_one() {
+                continue;
+            }
+            f.write_str(match notation {
+                Notation::Plain => "*",
+                Notation::Latex => " ",
+            })?;
+        }
+        write_monomial(f, &m, notation)?;
+    }
+    if first {
+        f.write_str("0")?;
+    }
+    Ok(())
+}
+
+/// LaTeX rendering of a polynomial or a basis;
+/// see `Polynomial::latex` and `Basis::latex`.
+#[derive(Debug, Clone, Copy)]
+pub struct Latex<T>(T);
+
+/// Display adapter for a list of polynomials such as a Gröbner basis, printed as `{f, g, ...}`.
+#[derive(Debug, Clone, Copy)]
+pub struct Basis<'a, P>(pub &'a [P]);
+
+impl<'a, P> Basis<'a, P> {
+    pub fn latex(self) -> Latex<Basis<'a, P>> {
+        Latex(self)
+    }
+}
+
+impl<P: Polynomial> Latex<&P>
+where
+    Scalar<P::Coeff>: Mul<P, Output = P>,
+{
+    pub(crate) fn new(p: &P) -> Latex<&P> {
+        Latex(p)
+    }
+}
+
+impl<P> fmt::Display for Latex<&P>
+where
+    P: Polynomial,
+    P::Coeff: DisplayCoeff,
+    Scalar<P::Coeff>: Mul<P, Output = P>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_polynomial(f, self.0, Notation::Latex)
+    }
+}
+
+impl<'a, P> fmt::Display for Basis<'a, P>
+where
+    P: Polynomial,
+    P::Coeff: DisplayCoeff,
+    Scalar<P::Coeff>: Mul<P, Output = P>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_basis(f, self.0, "{", "}", Notation::Plain)
+    }
+}
+
+impl<'a, P> fmt::Display for Latex<Basis<'a, P>>
+where
+    P: Polynomial,
+    P::Coeff: DisplayCoeff,
+    Scalar<P::Coeff>: Mul<P, Output = P>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_basis(f, (self.0).0, "\\left\\{", "\\right\\}", Notation::Latex)
+    }
+}
+
+fn write_basis<P, W>(
+    f: &mut W,
+    ps: &[P],
+    open: &str,
+    close: &str,
+    notation: Notation,
+) -> fmt::Result
+where
+    P: Polynomial,
+    P::Coeff: DisplayCoeff,
+    Scalar<P::Coeff>: Mul<P, Output = P>,
+    W: fmt::Write,
+{
+    f.write_str(open)?;
+    for (i, p) in ps.iter().enumerate() {
+        if i > 0 {
+            f.write_str(", ")?;
+        }
+        write_polynomial(f, p, notation)?;
+    }
+    f.write_str(close)
+}
+
+impl<R: Ring + DisplayCoeff, X: Monomial> fmt::Display for Ordpol<R, X> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_polynomial(f, self, Notation::Plain)
+    }
+}
+
+impl<R: Ring + DisplayCoeff> fmt::Display for Unipol<R> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_polynomial(f, self, Notation::Plain)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_rational::*;
+
+    type Pol = Ordpol<Rational, Grevlex3>;
+
+    #[test]
+    fn display_ordpol() {
+        let f: Pol = "3/2*x^2*y - z^3 + y - 1".parse().unwrap();
+        assert_eq!(f.to_string(), "3/2*x^2*y - z^3 + y - 1");
+        assert_eq!(
+            f.latex().to_string(),
+            "\\frac{3}{2} x^{2} y - z^{3} + y - 1"
+        );
+        assert_eq!(Pol::zero().to_string(), "0");
+        assert_eq!(Pol::one().to_string(), "1");
+        assert_eq!((-Pol::one()).to_string(), "-1");
+    }
+
+    #[test]
+    fn display_unipol() {
+        let f: Unipol<Rational> = "x^3 - 2*x + 1/3".parse().unwrap();
+        assert_eq!(f.to_string(), "x^3 - 2*x + 1/3");
+    }
+
+    #[test]
+    fn display_nested() {
+        let x: Unipol<Pol> = Unipol::x();
+        let c: Pol = "x - y".parse().unwrap();
+        let f = Scalar(c) * x.clone() + Unipol::from_coeff("-z".parse().unwrap());
+        assert_eq!(f.to_string(), "(x - y)*x - z");
+
+        let c: Pol = "1/2*x - y".parse().unwrap();
+        let f = Scalar(c) * x.clone() * x - Unipol::from_coeff("3/4*z".parse().unwrap());
+        assert_eq!(f.to_string(), "(1/2*x - y)*x^2 - 3/4*z");
+        assert_eq!(
+            f.latex().to_string(),
+            "\\left(\\frac{1}{2} x - y\\right) x^{2} - \\frac{3}{4} z"
+        );
+    }
+
+    #[test]
+    fn display_monomial_and_basis() {
+        let m = Grevlex3::X * Grevlex3::X * Grevlex3::Z;
+        assert_eq!(m.to_string(), "x^2*z");
+        assert_eq!(Grevlex3::one().to_string(), "1");
+        let gb: Vec<Pol> = vec!["x*y - 1".parse().unwrap(), "y^2 - x".parse().unwrap()];
+        assert_eq!(Basis(&gb).to_string(), "{x*y - 1, y^2 - x}");
+        assert_eq!(
+            Basis(&gb).latex().to_string(),
+            "\\left\\{x y - 1, y^{2} - x\\right\\}"
+        );
+    }
+}
@@ -0,0 +1,372 @@
+//! Greatest common divisors of multivariate polynomials.
+
+use crate::monomial::*;
+use crate::polynomial::groebner::multimodular::{residue, word_primes, Group, MAX_PRIMES};
+use crate::polynomial::{Ordpol, Polynomial, Unipol};
+use crate::ring::*;
+use num::bigint::BigInt;
+use num_integer::Integer;
+use num_traits::*;
+use std::collections::BTreeMap;
+
+impl<R: RationalCoeff, X: Monomial> GcdDomain for Ordpol<R, X> {
+    /// Gcd by a modular algorithm over integers and rationals (see `modular_gcd`),
+    /// and otherwise by the recursive subresultant algorithm, with a heuristic fast path.
+    ///
+    /// In the latter, both polynomials are seen as univariate in one of their variables,
+    /// with coefficients in the others: the gcd of their contents is computed recursively.
+    /// That of their primitive parts is first bounded by evaluating the other variables
+    /// (see `heuristic_gcd`), which settles the common cases of coprime polynomials
+    /// and of one dividing the other. Otherwise it is the primitive part of the last subresultant,
+    /// whose coefficients may grow large.
+    /// The result is normalised by the unit part of its leading coefficient,
+    /// i.e. it is monic over fields and has a positive leading coefficient over integers.
+    fn gcd_domain(&self, other: &Self) -> Self {
+        if let Some(h) = modular_gcd(self, other) {
+            return h;
+        }
+        let v = match X::variables()
+            .into_iter()
+            .find(|v| occurs(self, *v) || occurs(other, *v))
+        {
+            Some(v) => v,
+            None => {
+                let zero = R::zero();
+                let a = self.lead_coeff().unwrap_or(&zero);
+                let b = other.lead_coeff().unwrap_or(&zero);
+                return Ordpol::from_coeff(a.gcd_domain(b));
+            }
+        };
+        let f = to_univariate(self, v);
+        let g = to_univariate(other, v);
+        let c = f.content().gcd_domain(&g.content());
+        let (f, g) = (f.primitive_part(), g.primitive_part());
+        let h = heuristic_gcd(&f, &g, v).unwrap_or_else(|| match f.subresultants(&g).pop() {
+            Some(h) if h.degree() != Some(0) => from_univariate(h.primitive_part(), v),
+            _ => Ordpol::one(),
+        });
+        normalise(c * h)
+    }
+
+    fn lcm_domain(&self, other: &Self) -> Self {
+        if self.is_zero() || other.is_zero() {
+            return Ordpol::zero();
+        }
+        normalise(self.exact_div(&self.gcd_domain(other)).unwrap() * other.clone())
+    }
+}
+
+/// Gcd of non-zero `f` and `g` with rational coefficients, if it is found modulo primes.
+///
+/// Both are scaled to primitive integer polynomials, whose gcd is computed by `integer_gcd`,
+/// and multiplied back by the gcd of the contents of `f` and `g`.
+/// Fails on other coefficients, or if a coefficient of the gcd does not fit in `R`.
+fn modular_gcd<R, X>(f: &Ordpol<R, X>, g: &Ordpol<R, X>) -> Option<Ordpol<R, X>>
+where
+    R: RationalCoeff,
+    X: Monomial,
+{
+    if f.is_zero() || g.is_zero() {
+        return None;
+    }
+    let h = integer_gcd(&integral(f)?, &integral(g)?)?;
+    let h = h
+        .terms()
+        .into_iter()
+        .map(|(m, c)| Some((m, R::from_integer(c)?)))
+        .collect::<Option<_>>()?;
+    let c = f.content().gcd_domain(&g.content());
+    Some(normalise(Ordpol::from_coeff(c) * Ordpol::from_terms(h)))
+}
+
+/// Primitive integer polynomial associate to `f`, if its coefficients are rational.
+fn integral<R: RationalCoeff, X: Monomial>(f: &Ordpol<R, X>) -> Option<Ordpol<BigInt, X>> {
+    let terms = f
+        .terms()
+        .into_iter()
+        .map(|(m, c)| Some((m, c.to_rational()?)))
+        .collect::<Option<Vec<_>>>()?;
+    let lcm = terms.iter().fold(BigInt::one(), |l, (_, (_, d))| l.lcm(d));
+    let f: Ordpol<BigInt, X> = Ordpol::from_terms(
+        terms
+            .into_iter()
+            .map(|(m, (n, d))| (m, n * (&lcm / d)))
+            .collect(),
+    );
+    Some(f.primitive_part())
+}
+
+/// Gcd of the non-zero primitive integer polynomials `a` and `b`, up to sign,
+/// by Chinese remaindering of its images modulo primes.
+///
+/// Primes dividing a leading coefficient are skipped. For the others, the leading monomial
+/// of the image gcd is a multiple of that of the gcd, and the primes giving
+/// the smallest one so far are kept. Their monic images are scaled by the gcd
+/// of the leading coefficients, which the leading coefficient of the gcd divides,
+/// and lifted to integers in the symmetric range. Once the primitive part of the lift
+/// stabilises, it is the gcd if it divides both `a` and `b`; otherwise more primes are used.
+/// Fails if that does not happen within `MAX_PRIMES` primes.
+fn integer_gcd<X: Monomial>(
+    a: &Ordpol<BigInt, X>,
+    b: &Ordpol<BigInt, X>,
+) -> Option<Ordpol<BigInt, X>> {
+    let (la, lb) = (a.lead_coeff()?, b.lead_coeff()?);
+    let gamma = la.gcd(lb);
+    let primes = word_primes().filter(|p| {
+        let p = BigInt::from(*p);
+        !la.is_multiple_of(&p) && !lb.is_multiple_of(&p)
+    });
+    let mut group: Option<Group<X, Ordpol<BigInt, X>>> = None;
+    for p in primes.take(MAX_PRIMES) {
+        let image = RuntimeModulus::with(p, || {
+            let reduce = |f: &Ordpol<BigInt, X>| -> Ordpol<Fp<RuntimeModulus>, X> {
+                Ordpol::from_terms(
+                    f.terms()
+                        .into_iter()
+                        .map(|(m, c)| (m, Fp::new(residue(c, p))))
+                        .collect(),
+                )
+            };
+            let scale = Fp::new(residue(&gamma, p));
+            reduce(a)
+                .gcd_domain(&reduce(b))
+                .terms()
+                .into_iter()
+                .map(|(m, c)| (m, (*c * scale).value()))
+                .collect::<BTreeMap<X, u64>>()
+        });
+        let lm = image.keys().next_back().unwrap().clone();
+        if lm.is_one() {
+            return Some(Ordpol::one());
+        }
+        match group.as_mut() {
+            Some(g) if g.lms[0] == lm => g.combine(&[image], p),
+            Some(g) if g.lms[0] < lm => continue,
+            _ => group = Some(Group::new(vec![lm], vec![image], p)),
+        }
+
+        let g = group.as_mut().unwrap();
+        let half = &g.modulus / BigInt::from(2);
+        let lift: Ordpol<BigInt, X> = Ordpol::from_terms(
+            g.residues[0]
+                .iter()
+                .map(|(m, c)| {
+                    let c = if *c > half { c - &g.modulus } else { c.clone() };
+                    (m.clone(), c)
+                })
+                .collect(),
+        );
+        let candidate = lift.primitive_part();
+        if g.candidate.as_ref() == Some(&candidate)
+            && a.exact_div(&candidate).is_some()
+            && b.exact_div(&candidate).is_some()
+        {
+            return Some(candidate);
+        }
+        g.candidate = Some(candidate);
+    }
+    None
+}
+
+/// Gcd of the primitive polynomials `f` and `g` in `v`, up to a unit, if it is easily found.
+///
+/// The other variables are replaced by small integers. If the leading coefficients
+/// do not vanish, the degree of the gcd of the images bounds that of the gcd of `f` and `g`.
+/// A bound of zero proves them coprime, and a bound equal to the degree of `f` or `g`
+/// leaves it as the only candidate, which is checked by trial division.
+fn heuristic_gcd<R, X>(
+    f: &Unipol<Ordpol<R, X>>,
+    g: &Unipol<Ordpol<R, X>>,
+    v: X::Var,
+) -> Option<Ordpol<R, X>>
+where
+    R: EuclideanDomain,
+    X: Monomial,
+{
+    let vars = X::variables();
+    let point = |w: X::Var| R::from_nat(vars.iter().position(|u| *u == w).unwrap() + 2);
+    let image = |p: &Unipol<Ordpol<R, X>>| {
+        let q = Unipol::from_coeffs(p.iter().map(|(_, c)| c.eval(&point)).collect());
+        Some(q).filter(|q| q.degree() == p.degree())
+    };
+    let (fa, ga) = (image(f)?, image(g)?);
+    let bound = fa.subresultants(&ga).pop()?.degree()?;
+    if bound == 0 {
+        return Some(Ordpol::one());
+    }
+    let (f, g) = (from_univariate(f.clone(), v), from_univariate(g.clone(), v));
+    if Some(bound) == fa.degree() && g.exact_div(&f).is_some() {
+        Some(f)
+    } else if Some(bound) == ga.degree() && f.exact_div(&g).is_some() {
+        Some(g)
+    } else {
+        None
+    }
+}
+
+fn occurs<R: Ring, X: Monomial>(f: &Ordpol<R, X>, v: X::Var) -> bool {
+    f.terms().keys().any(|m| m.exponent(v) > 0)
+}
+
+/// `f` as a polynomial in `v`, with coefficients in the other variables.
+fn to_univariate<R: Ring, X: Monomial>(f: &Ordpol<R, X>, v: X::Var) -> Unipol<Ordpol<R, X>> {
+    let mut coeffs: Vec<BTreeMap<X, R>> = Vec::new();
+    for (m, c) in f.terms() {
+        let k = m.exponent(v);
+        if coeffs.len() <= k {
+            coeffs.resize(k + 1, BTreeMap::new());
+        }
+        let rest = (m / X::from_exponents(&[(v, k)])).unwrap();
+        coeffs[k].insert(rest, c.clone());
+    }
+    Unipol::from_coeffs(coeffs.into_iter().map(Ordpol::from_terms).collect())
+}
+
+fn from_univariate<R: Ring, X: Monomial>(f: Unipol<Ordpol<R, X>>, v: X::Var) -> Ordpol<R, X> {
+    f.into_iter()
+        .map(|(Power(k), c)| Ordpol::from_monomial(X::from_exponents(&[(v, k)])) * c)
+        .fold(Ordpol::zero(), |a, b| a + b)
+}
+
+/// Divides by the unit part of the leading coefficient.
+fn normalise<R: EuclideanDomain, X: Monomial>(f: Ordpol<R, X>) -> Ordpol<R, X> {
+    match f.lead_coeff().map(R::unit_part) {
+        Some(u) if !u.is_one() => Ordpol::from_terms(
+            f.terms()
+                .into_iter()
+                .map(|(m, c)| (m, c.exact_div(&u).unwrap()))
+                .collect(),
+        ),
+        _ => f,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monomial::grevlex3::{X, Y, Z};
+    use num::bigint::BigInt;
+    use num_rational::Rational;
+
+    type P = Ordpol<isize, Grevlex3>;
+    type Q = Ordpol<Rational, Grevlex3>;
+
+    fn xyz<R: Ring>() -> (
+        Ordpol<R, Grevlex3>,
+        Ordpol<R, Grevlex3>,
+        Ordpol<R, Grevlex3>,
+    ) {
+        (Ordpol::var(X), Ordpol::var(Y), Ordpol::var(Z))
+    }
+
+    #[test]
+    fn integer_gcd() {
+        let (x, y, z) = xyz::<isize>();
+        let two = P::from_int(2);
+        let a = x.clone() + y.clone();
+        let b = x.clone() - two.clone() * z.clone();
+        let f = P::from_int(3) * a.clone() * b.clone() * b.clone();
+        let g = P::from_int(-6) * a.clone() * (y.clone() * y.clone() + z.clone()) * b.clone();
+        let h = P::from_int(3) * a.clone() * b.clone();
+        assert_eq!(f.gcd_domain(&g), h);
+        assert_eq!(g.gcd_domain(&f), h);
+        assert_eq!(
+            f.lcm_domain(&g),
+            P::from_int(6) * a * b.clone() * b * (y.clone() * y + z)
+        );
+        assert_eq!(f.gcd_domain(&P::zero()), f);
+        assert_eq!((-f.clone()).gcd_domain(&P::zero()), f);
+        assert_eq!(f.gcd_domain(&(x.clone() * x + P::one())), P::one());
+        assert_eq!(P::from_int(4).gcd_domain(&P::from_int(-6)), two);
+        assert!(P::zero().gcd_domain(&P::zero()).is_zero());
+    }
+
+    #[test]
+    fn rational_gcd() {
+        let (x, y, z) = xyz::<Rational>();
+        let half = Q::from_coeff(Rational::new(1, 2));
+        let f = x.clone() * x.clone() - y.clone() * y.clone();
+        let g = half * (x.clone() + y.clone()) * (x.clone() + y.clone()) * z.clone();
+        assert_eq!(f.gcd_domain(&g), x.clone() + y);
+        assert_eq!(f.gcd_domain(&z), Q::one());
+        assert_eq!(Q::from_int(4).gcd_domain(&Q::from_int(6)), Q::one());
+        assert_eq!(g.gcd_domain(&(Q::from_int(3) * z.clone())), z);
+    }
+
+    #[test]
+    fn common_factor_is_found() {
+        let (x, y, z) = xyz::<isize>();
+        let h = x.clone() * y.clone() - z.clone() * z.clone() + P::from_int(2);
+        let cofactors = [
+            (x.clone() + P::one(), y.clone() * y.clone() - x.clone()),
+            (z.clone().pow(3), x.clone() * z.clone() + y.clone()),
+            (P::from_int(2) * y.clone() - P::one(), x.clone() - y),
+        ];
+        for (a, b) in cofactors.iter() {
+            let f = h.clone() * a.clone();
+            let g = h.clone() * b.clone();
+            assert_eq!(f.gcd_domain(&g), h);
+        }
+    }
+
+    #[test]
+    fn heuristic_shortcuts() {
+        // Dense polynomials, whose subresultant sequence has huge coefficients.
+        type B = Ordpol<BigInt, Grevlex3>;
+        let (x, y, z) = xyz::<BigInt>();
+        let f = (x.clone() + y.clone() + z.clone() + B::one()).pow(6) + x.clone();
+        let g = (x.clone() - B::from_int(2) * y.clone() + B::from_int(3) * z).pow(6) + y;
+        assert_eq!(f.gcd_domain(&g), B::one());
+        let h = f.clone() * (x.clone() * x - B::from_int(7));
+        assert_eq!(f.gcd_domain(&h), f);
+        assert_eq!(h.gcd_domain(&(B::from_int(-2) * f.clone())), f);
+    }
+
+    #[test]
+    fn modular_common_factor() {
+        // Neither cofactor divides the other, so the heuristic does not apply.
+        type B = Ordpol<BigInt, Grevlex3>;
+        let (x, y, z) = xyz::<BigInt>();
+        let h = B::from_int(2) * (x.clone() + y.clone() + z.clone() + B::one()).pow(3)
+            - B::from_int(3) * x.clone();
+        let a = (x.clone() - B::from_int(2) * y.clone() + B::from_int(3) * z.clone()).pow(2)
+            + y.clone();
+        let b = (x.clone() + B::from_int(2) * z.clone()).pow(2) - y * z + B::from_int(5);
+        let (f, g) = (h.clone() * a, B::from_int(-6) * h.clone() * b);
+        assert_eq!(f.gcd_domain(&g), h);
+
+        // Over the rationals, the subresultants would overflow.
+        let (x, _, _) = xyz::<Rational>();
+        let q = |f: &B| {
+            Q::from_terms(
+                f.terms()
+                    .into_iter()
+                    .map(|(m, c)| (m, Rational::from_integer(c.to_isize().unwrap())))
+                    .collect(),
+            )
+        };
+        let half = Q::from_coeff(Rational::new(1, 2));
+        assert_eq!(
+            (half.clone() * q(&f)).gcd_domain(&(q(&g) * x)),
+            half * q(&h)
+        );
+    }
+
+    #[test]
+    fn content_and_primitive_part() {
+        let (x, y, _) = xyz::<isize>();
+        let f = P::from_int(6) * x.clone() * x.clone() - P::from_int(4) * y.clone();
+        assert_eq!(f.content(), 2);
+        assert_eq!(
+            f.primitive_part(),
+            P::from_int(3) * x.clone() * x - P::from_int(2) * y
+        );
+        assert_eq!(P::zero().content(), 0);
+        assert!(P::zero().primitive_part().is_zero());
+
+        let (x, _, _) = xyz::<Rational>();
+        let g = Q::from_coeff(Rational::new(2, 3)) * x;
+        assert_eq!(g.content(), Rational::one());
+        assert_eq!(g.primitive_part(), g);
+    }
+}
@@ -17,7 +17,7 @@ where
 {
     let lm_f = f.lead_monom().unwrap_or_else(One::one);
     let lm_g = g.lead_monom().unwrap_or_else(One::one);
-    let lcm_fg = lm_f.lcm(lm_g);
+    let lcm_fg = lm_f.clone().lcm(lm_g.clone());
     let total_f = f.total_deg() - lm_f.total_deg();
     let total_g = g.total_deg() - lm_g.total_deg();
     cmp::Reverse(total_f.max(total_g) + lcm_fg.total_deg())
@@ -33,61 +33,159 @@ where
     buchberger_with(sugar, ideal)
 }
 
-/// Buchberger algorithm with coprimarity and syzygy criterion,
+/// Buchberger algorithm with Gebauer–Möller criteria,
 /// which accepts selection strategy as a weighting function.
 /// This function processes critical pairs in heavier-first manner.
-pub fn buchberger_with<W: Ord, F, P: Polynomial>(calc_weight: F, mut ideal: Vec<P>) -> Vec<P>
+pub fn buchberger_with<W: Ord, F, P: Polynomial>(calc_weight: F, ideal: Vec<P>) -> Vec<P>
 where
     F: Fn(&P, &P) -> W + Copy,
     Scalar<<P as Polynomial>::Coeff>: Mul<P, Output = P>,
     P::Coeff: Field,
 {
+    buchberger_with_stats(calc_weight, ideal).0
+}
+
+/// Same as `buchberger_with`, but also reports how many critical pairs
+/// were discarded by each criterion.
+pub fn buchberger_with_stats<W: Ord, F, P: Polynomial>(
+    calc_weight: F,
+    ideal: Vec<P>,
+) -> (Vec<P>, PairStatistics)
+where
+    F: Fn(&P, &P) -> W + Copy,
+    Scalar<<P as Polynomial>::Coeff>: Mul<P, Output = P>,
+    P::Coeff: Field,
+{
+    let mut stats = PairStatistics::default();
     let mut pairs = BinaryHeap::new();
-    for i in 0..ideal.len() {
-        for j in 0..i {
+    let mut basis: Vec<P> = Vec::with_capacity(ideal.len());
+    let mut lms: Vec<P::Monomial> = Vec::with_capacity(ideal.len());
+
+    // Registers a new element, pruning the pending pairs and the new ones.
+    let mut insert = |basis: &mut Vec<P>, pairs: &mut BinaryHeap<_>, g: P| {
+        lms.push(g.lead_monom().unwrap());
+        basis.push(g);
+        let before = pairs.len();
+        pairs.retain(|Entry(_, (i, j))| !pairs::is_chain_redundant(&lms, *i, *j));
+        stats.chain_old += before - pairs.len();
+        for (i, j, _) in pairs::new_pairs(&lms, &mut stats) {
             // Registering ciritcal pairs, with a weight for selection strategy
-            pairs.push(Entry(calc_weight(&ideal[i], &ideal[j]), (i, j)))
+            pairs.push(Entry(calc_weight(&basis[i], &basis[j]), (i, j)));
         }
+    };
+    for f in ideal.into_iter().filter(|f| !f.is_zero()) {
+        insert(&mut basis, &mut pairs, f);
     }
-    let mut n = ideal.len();
+
+    let mut reduced = 0;
     while let Some(Entry(_, (i, j))) = pairs.pop() {
-        let (lt_f, lt_g) = (
-            &ideal[0].lead_monom().unwrap(),
-            &ideal[1].lead_monom().unwrap(),
-        );
-        let lcm_fg = lt_f.lcm(*lt_g);
-
-        // Primarity check
-        if lcm_fg == *lt_f * *lt_g {
-            continue;
+        // S-test
+        reduced += 1;
+        let (f, g) = (basis[i].clone(), basis[j].clone());
+        let (_, s) = f.spol(g).div_mod_polys(basis.clone());
+        if !s.is_zero() {
+            insert(&mut basis, &mut pairs, s);
         }
+    }
+    stats.reduced = reduced;
+    (basis, stats)
+}
 
-        // Syzygy test
-        let syz = ideal.iter().enumerate().any(|(l, h)| {
-            let [k1, l1] = [i.min(i), i.max(l)];
-            let [k2, l2] = [j.min(j), j.max(l)];
-            let distinct = pairs
-                .iter()
-                .all(|Entry(_, (k, o))| (*k, *o) != (k1, l1) && (*k, *o) != (k2, l2));
-            l != i && l != j && distinct && h.lead_monom().unwrap().divides(&lcm_fg)
-        });
-        if syz {
-            continue;
-        }
+/// Reduced Gröbner basis of the ideal, computed by Buchberger algorithm.
+/// The result is sorted in increasing order of leading monomials.
+#[inline]
+pub fn reduced_groebner_basis<P: Polynomial>(ideal: Vec<P>) -> Vec<P>
+where
+    Scalar<<P as Polynomial>::Coeff>: Mul<P, Output = P>,
+    P::Coeff: Field,
+{
+    interreduce(buchberger(ideal))
+}
 
-        // S-test
-        let (f, g) = (ideal[i].clone(), ideal[j].clone());
-        let (_, s) = f.spol(g).div_mod_polys(ideal.clone());
-        if !s.is_zero() {
-            ideal.push(s);
-            for k in 0..n {
-                pairs.push(Entry(calc_weight(&ideal[k], &ideal[n]), (n, k)));
-            }
-            n += 1;
+/// Whether `gb` is a Gröbner basis of an ideal containing `ideal`: every S-polynomial
+/// of a pair not discarded by the product criterion, and every element of `ideal`,
+/// reduces to zero modulo `gb`. Zero elements of `gb` are ignored.
+pub(crate) fn is_groebner_basis_of<P: Polynomial>(gb: &[P], ideal: &[P]) -> bool
+where
+    Scalar<<P as Polynomial>::Coeff>: Mul<P, Output = P>,
+    P::Coeff: Field,
+{
+    let gb: Vec<P> = gb.iter().filter(|g| !g.is_zero()).cloned().collect();
+    let reduces = |f: P| f.div_mod_polys(gb.clone()).1.is_zero();
+    (0..gb.len()).all(|i| {
+        (0..i).all(|j| {
+            let (m, n) = (gb[i].lead_monom().unwrap(), gb[j].lead_monom().unwrap());
+            pairs::is_coprime(&m, &n) || reduces(gb[i].clone().spol(gb[j].clone()))
+        })
+    }) && ideal.iter().cloned().all(reduces)
+}
+
+/// Turns a Gröbner basis into the reduced one:
+/// drops the elements with redundant leading monomials, makes the rest monic,
+/// and reduces their tails. The result is sorted in increasing order of leading monomials.
+pub fn interreduce<P: Polynomial>(gb: Vec<P>) -> Vec<P>
+where
+    Scalar<<P as Polynomial>::Coeff>: Mul<P, Output = P>,
+    P::Coeff: Field,
+{
+    let mut gb: Vec<_> = gb
+        .into_iter()
+        .filter_map(|g| g.lead_monom().map(|m| (m, g)))
+        .collect();
+    gb.sort_by(|(m, _), (n, _)| m.cmp(n));
+
+    // Minimisation: divisors come before their multiples.
+    let mut minimal: Vec<(P::Monomial, P)> = Vec::with_capacity(gb.len());
+    for (m, g) in gb {
+        if minimal.iter().all(|(n, _)| !n.divides(&m)) {
+            minimal.push((m, g));
         }
     }
-    ideal
+    let minimal: Vec<P> = minimal
+        .into_iter()
+        .map(|(_, g)| {
+            let c = g.lead_coeff().unwrap().clone();
+            Scalar(c.recip()) * g
+        })
+        .collect();
+
+    // Tail reduction: leading terms are irreducible by the others, hence untouched.
+    (0..minimal.len())
+        .map(|i| {
+            let others: Vec<P> = minimal
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .map(|(_, g)| g.clone())
+                .collect();
+            minimal[i].clone().div_mod_polys(others).1
+        })
+        .collect()
 }
 
 pub mod signature;
 pub use self::signature::f5;
+
+pub mod elimination;
+pub use self::elimination::eliminate;
+
+pub mod fglm;
+pub use self::fglm::fglm;
+
+pub mod f4;
+pub use self::f4::f4;
+
+pub mod pairs;
+pub use self::pairs::PairStatistics;
+
+pub mod multimodular;
+pub use self::multimodular::{modular_groebner_basis, NoConvergence};
+
+pub mod hilbert;
+pub use self::hilbert::HilbertSeries;
+
+pub mod quotient;
+pub use self::quotient::QuotientAlgebra;
+
+#[cfg(test)]
+mod tests;
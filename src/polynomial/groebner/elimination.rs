@@ -0,0 +1,29 @@
+use crate::monomial::Monomial;
+use crate::polynomial::groebner::reduced_groebner_basis;
+use crate::polynomial::Polynomial;
+use crate::ring::*;
+use crate::scalar::*;
+use std::ops::*;
+
+/// Generators of the elimination ideal, i.e. the intersection of the ideal
+/// with the subring of polynomials free from `vars`.
+///
+/// The monomial order of `P` must be an elimination order for `vars`,
+/// such as lex with `vars` first, or a block order (e.g. `Elim2Grevlex4`)
+/// whose first block consists of `vars`. The result is a reduced Gröbner basis
+/// of the elimination ideal.
+pub fn eliminate<P>(ideal: Vec<P>, vars: &[<P::Monomial as Monomial>::Var]) -> Vec<P>
+where
+    P: Polynomial,
+    Scalar<P::Coeff>: Mul<P, Output = P>,
+    P::Coeff: Field,
+{
+    reduced_groebner_basis(ideal)
+        .into_iter()
+        .filter(|g| {
+            g.terms()
+                .keys()
+                .all(|m| vars.iter().all(|v| m.exponent(*v) == 0))
+        })
+        .collect()
+}
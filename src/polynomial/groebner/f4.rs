@@ -0,0 +1,130 @@
+use crate::monomial::Monomial;
+use crate::polynomial::groebner::pairs::{self, PairStatistics};
+use crate::polynomial::Polynomial;
+use crate::ring::*;
+use crate::scalar::*;
+use std::collections::{BTreeMap, BTreeSet};
+use std::ops::*;
+
+/// Faugère's F4 algorithm with the normal selection strategy.
+///
+/// All the critical pairs of the least degree are processed at once:
+/// their halves are gathered together with the reducers found by symbolic preprocessing,
+/// and the resulting Macaulay matrix is brought into echelon form.
+/// Rows whose leading monomials are new are added to the basis.
+/// Like `buchberger`, the result is a (not necessarily reduced) Gröbner basis;
+/// apply `interreduce` to get the reduced one.
+pub fn f4<P: Polynomial>(ideal: Vec<P>) -> Vec<P>
+where
+    Scalar<P::Coeff>: Mul<P, Output = P>,
+    P::Coeff: Field,
+{
+    let mut basis: Vec<P> = Vec::with_capacity(ideal.len());
+    let mut lms: Vec<P::Monomial> = Vec::with_capacity(ideal.len());
+    let mut pairs: Vec<(usize, usize, P::Monomial)> = Vec::new();
+    for f in ideal.into_iter().filter(|f| !f.is_zero()) {
+        add_element(&mut basis, &mut lms, &mut pairs, monic(f));
+    }
+
+    while !pairs.is_empty() {
+        let deg = pairs.iter().map(|(_, _, m)| m.total_deg()).min().unwrap();
+        let (selected, rest): (Vec<_>, Vec<_>) = pairs
+            .into_iter()
+            .partition(|(_, _, m)| m.total_deg() == deg);
+        pairs = rest;
+
+        let mut halves: BTreeSet<(P::Monomial, usize)> = BTreeSet::new();
+        for (i, j, lcm) in selected {
+            for k in [i, j] {
+                let lm = basis[k].lead_monom().unwrap();
+                halves.insert(((lcm.clone() / lm).unwrap(), k));
+            }
+        }
+        let rows = symbolic_preprocessing(&basis, halves);
+        let leads: BTreeSet<P::Monomial> = rows.iter().filter_map(P::lead_monom).collect();
+        for (m, row) in echelon_form(rows) {
+            if !leads.contains(&m) {
+                add_element(&mut basis, &mut lms, &mut pairs, row);
+            }
+        }
+    }
+    basis
+}
+
+/// Adds `g` to the basis, updating the critical pairs by the Gebauer–Möller criteria.
+fn add_element<P: Polynomial>(
+    basis: &mut Vec<P>,
+    lms: &mut Vec<P::Monomial>,
+    pairs: &mut Vec<(usize, usize, P::Monomial)>,
+    g: P,
+) where
+    Scalar<P::Coeff>: Mul<P, Output = P>,
+{
+    lms.push(g.lead_monom().unwrap());
+    basis.push(g);
+    pairs.retain(|(i, j, _)| !pairs::is_chain_redundant(lms, *i, *j));
+    pairs.extend(pairs::new_pairs(lms, &mut PairStatistics::default()));
+}
+
+/// Rows `u * g` of the Macaulay matrix for the given pairs `(u, index of g)`,
+/// closed under adding a reducer for every monomial divisible by a leading monomial of the basis.
+fn symbolic_preprocessing<P: Polynomial>(
+    basis: &[P],
+    halves: BTreeSet<(P::Monomial, usize)>,
+) -> Vec<P>
+where
+    Scalar<P::Coeff>: Mul<P, Output = P>,
+{
+    let mut rows: Vec<P> = halves
+        .into_iter()
+        .map(|(u, k)| P::from_monomial(u) * basis[k].clone())
+        .collect();
+    let mut done: BTreeSet<P::Monomial> = rows.iter().filter_map(P::lead_monom).collect();
+    let mut todo: BTreeSet<P::Monomial> = rows
+        .iter()
+        .flat_map(|r| r.terms().into_keys())
+        .filter(|m| !done.contains(m))
+        .collect();
+    while let Some(m) = todo.pop_last() {
+        done.insert(m.clone());
+        let reducer = basis
+            .iter()
+            .find_map(|g| (m.clone() / g.lead_monom().unwrap()).map(|u| (u, g)));
+        if let Some((u, g)) = reducer {
+            let row = P::from_monomial(u) * g.clone();
+            todo.extend(row.terms().into_keys().filter(|m| !done.contains(m)));
+            rows.push(row);
+        }
+    }
+    rows
+}
+
+/// Row echelon form of the Macaulay matrix, as monic rows keyed by their leading monomials.
+fn echelon_form<P: Polynomial>(rows: Vec<P>) -> BTreeMap<P::Monomial, P>
+where
+    Scalar<P::Coeff>: Mul<P, Output = P>,
+    P::Coeff: Field,
+{
+    let mut pivots: BTreeMap<P::Monomial, P> = BTreeMap::new();
+    for mut row in rows {
+        while let Some((m, c)) = row.lead_term().map(|(m, c)| (m, c.clone())) {
+            match pivots.get(&m) {
+                Some(pivot) => row -= Scalar(c) * pivot.clone(),
+                None => {
+                    pivots.insert(m, monic(row));
+                    break;
+                }
+            }
+        }
+    }
+    pivots
+}
+
+fn monic<P: Polynomial>(f: P) -> P
+where
+    Scalar<P::Coeff>: Mul<P, Output = P>,
+    P::Coeff: Field,
+{
+    let c = f.lead_coeff().unwrap().clone();
+    Scalar(c.recip()) * f
+}
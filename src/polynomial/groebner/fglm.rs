@@ -0,0 +1,122 @@
+use crate::monomial::Monomial;
+use crate::polynomial::Polynomial;
+use crate::ring::*;
+use crate::scalar::*;
+use num_traits::*;
+use std::collections::{BTreeMap, BTreeSet};
+use std::ops::*;
+
+/// Sparse vector indexed by monomials.
+type Vector<X, K> = BTreeMap<X, K>;
+
+/// Echelon rows keyed by pivot, each with the combination of target monomials it comes from.
+type Rows<X, Y, K> = BTreeMap<X, (Vector<X, K>, Vector<Y, K>)>;
+
+/// FGLM algorithm: converts the reduced Gröbner basis `gb` of a zero-dimensional ideal
+/// into the reduced Gröbner basis of the same ideal w.r.t. the monomial order of `Q`.
+///
+/// The monomial types of `P` and `Q` are identified variable by variable,
+/// following the order of `Monomial::variables()`; e.g. a `Grevlex3` basis
+/// converts to `Lex3`. The result is sorted in increasing order of leading monomials,
+/// as in `reduced_groebner_basis`. Returns `None` if the ideal is not zero-dimensional.
+pub fn fglm<P, Q>(gb: &[P]) -> Option<Vec<Q>>
+where
+    P: Polynomial,
+    Q: Polynomial<Coeff = P::Coeff>,
+    Scalar<P::Coeff>: Mul<P, Output = P> + Mul<Q, Output = Q>,
+    P::Coeff: Field,
+{
+    let xs = <P::Monomial as Monomial>::variables();
+    let ys = <Q::Monomial as Monomial>::variables();
+    assert_eq!(xs.len(), ys.len(), "number of variables mismatch");
+    if !is_zero_dimensional(gb) {
+        return None;
+    }
+
+    let normal_form = |f: P| f.div_mod_polys(gb.to_vec()).1;
+
+    // Echelon form of the normal forms of the standard monomials found so far.
+    let mut rows: Rows<P::Monomial, Q::Monomial, P::Coeff> = BTreeMap::new();
+    let mut result: Vec<Q> = Vec::new();
+    // Monomials yet to be examined, with their normal forms, and all those ever queued.
+    let mut candidates: BTreeMap<Q::Monomial, P> = BTreeMap::new();
+    let mut queued: BTreeSet<Q::Monomial> = BTreeSet::new();
+    candidates.insert(Q::Monomial::one(), normal_form(P::one()));
+    queued.insert(Q::Monomial::one());
+
+    while let Some((t, nf)) = candidates.pop_first() {
+        if result.iter().any(|g| g.lead_monom().unwrap().divides(&t)) {
+            continue;
+        }
+
+        let mut vec: Vector<P::Monomial, P::Coeff> = nf
+            .terms()
+            .into_iter()
+            .map(|(m, c)| (m, c.clone()))
+            .collect();
+        let mut comb: Vector<Q::Monomial, P::Coeff> = BTreeMap::new();
+        comb.insert(t.clone(), P::Coeff::one());
+        reduce(&rows, &mut vec, &mut comb);
+
+        match vec.keys().next_back().cloned() {
+            // `comb` is a linear relation between `t` and smaller standard monomials.
+            None => result.push(Q::from_terms(comb)),
+            Some(pivot) => {
+                let c = vec[&pivot].clone().recip();
+                let vec = vec.into_iter().map(|(m, a)| (m, a * c.clone())).collect();
+                let comb = comb.into_iter().map(|(m, a)| (m, a * c.clone())).collect();
+                rows.insert(pivot, (vec, comb));
+                for (x, y) in xs.iter().zip(ys.iter()) {
+                    let u = t.clone() * Q::Monomial::var(*y);
+                    if queued.insert(u.clone()) {
+                        candidates.insert(u, normal_form(P::var(*x) * nf.clone()));
+                    }
+                }
+            }
+        }
+    }
+    Some(result)
+}
+
+/// Whether the ideal generated by the Gröbner basis `gb` is zero-dimensional,
+/// i.e. it is the whole ring or every variable has a pure power among the leading monomials.
+pub fn is_zero_dimensional<P>(gb: &[P]) -> bool
+where
+    P: Polynomial,
+    Scalar<P::Coeff>: Mul<P, Output = P>,
+{
+    let lms: Vec<_> = gb.iter().filter_map(|g| g.lead_monom()).collect();
+    if lms.iter().any(|m| m.is_one()) {
+        return true;
+    }
+    <P::Monomial as Monomial>::variables().into_iter().all(|v| {
+        lms.iter()
+            .any(|m| m.exponents().into_iter().all(|(w, n)| (w == v) == (n > 0)))
+    })
+}
+
+/// Reduces `vec` by the echelon rows, updating the combination `comb` accordingly.
+/// Rows have monic leading entries, so pivots are eliminated from the largest down.
+fn reduce<X, Y, K>(rows: &Rows<X, Y, K>, vec: &mut Vector<X, K>, comb: &mut Vector<Y, K>)
+where
+    X: Monomial,
+    Y: Monomial,
+    K: Field,
+{
+    for (pivot, (row, row_comb)) in rows.iter().rev() {
+        if let Some(a) = vec.get(pivot).cloned() {
+            axpy(vec, &a, row);
+            axpy(comb, &a, row_comb);
+        }
+    }
+}
+
+/// `v -= a * w`, dropping the entries that vanish.
+fn axpy<X: Ord + Clone, K: Field>(v: &mut Vector<X, K>, a: &K, w: &Vector<X, K>) {
+    for (m, b) in w {
+        let c = v.remove(m).unwrap_or_else(K::zero) - a.clone() * b.clone();
+        if !c.is_zero() {
+            v.insert(m.clone(), c);
+        }
+    }
+}
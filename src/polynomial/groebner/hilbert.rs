@@ -0,0 +1,272 @@
+//! Hilbert–Poincaré series of quotients by monomial ideals.
+//!
+//! Since `R/I` and `R/in(I)` have the same Hilbert function for a homogeneous ideal `I`,
+//! and the same affine Hilbert function for a degree-compatible order,
+//! everything is computed from the leading monomials of a Gröbner basis.
+
+use crate::monomial::Monomial;
+use crate::polynomial::{Polynomial, Unipol};
+use crate::ring::*;
+use crate::scalar::*;
+use num::bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::*;
+use std::ops::*;
+
+/// Hilbert–Poincaré series `Q(t) / (1 - t)^n` of `R/I`, where `R` has `n` variables.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HilbertSeries {
+    numerator: Unipol<BigInt>,
+    variables: usize,
+}
+
+impl HilbertSeries {
+    /// Series of `R/in(I)`, for a Gröbner basis `gb` of `I`.
+    pub fn from_groebner_basis<P: Polynomial>(gb: &[P]) -> HilbertSeries
+    where
+        Scalar<P::Coeff>: Mul<P, Output = P>,
+    {
+        let lms: Vec<P::Monomial> = gb.iter().filter_map(Polynomial::lead_monom).collect();
+        HilbertSeries::from_monomials(&lms)
+    }
+
+    /// Series of the quotient by the ideal generated by `monomials`.
+    pub fn from_monomials<X: Monomial>(monomials: &[X]) -> HilbertSeries {
+        let gens = monomials
+            .iter()
+            .map(|m| m.exponents().into_iter().map(|(_, n)| n).collect())
+            .collect();
+        HilbertSeries {
+            numerator: numerator(gens),
+            variables: X::variables().len(),
+        }
+    }
+
+    /// The numerator `Q(t)`.
+    pub fn numerator(&self) -> &Unipol<BigInt> {
+        &self.numerator
+    }
+
+    /// The number `n` of variables, i.e. the exponent of the denominator.
+    pub fn variables(&self) -> usize {
+        self.variables
+    }
+
+    /// `(h(t), d)` such that the series is `h(t) / (1 - t)^d` with `h(1) != 0`,
+    /// except for the zero series of the unit ideal, given as `(0, n)`.
+    pub fn reduced(&self) -> (Unipol<BigInt>, usize) {
+        let one_minus_t = Unipol::one() - Unipol::x();
+        let mut h = self.numerator.clone();
+        let mut d = self.variables;
+        while !h.is_zero() && d > 0 {
+            match h.exact_div(&one_minus_t) {
+                Some(q) => {
+                    h = q;
+                    d -= 1;
+                }
+                None => break,
+            }
+        }
+        (h, d)
+    }
+
+    /// Krull dimension of `R/I`; `None` for the unit ideal.
+    pub fn dimension(&self) -> Option<usize> {
+        match self.reduced() {
+            (ref h, _) if h.is_zero() => None,
+            (_, d) => Some(d),
+        }
+    }
+
+    /// Degree (multiplicity) of `R/I`, i.e. `h(1)`; zero for the unit ideal.
+    pub fn degree(&self) -> BigInt {
+        self.reduced().0.eval(|_| BigInt::one())
+    }
+
+    /// Value of the Hilbert function at `s`, the coefficient of `t^s` in the series.
+    pub fn hilbert_function(&self, s: usize) -> BigInt {
+        if self.variables == 0 {
+            return self
+                .numerator
+                .iter()
+                .nth(s)
+                .map_or(BigInt::zero(), |(_, q)| q.clone());
+        }
+        self.numerator
+            .iter()
+            .take_while(|(i, _)| i.0 <= s)
+            .map(|(i, q)| q * binomial(s - i.0 + self.variables - 1, self.variables - 1))
+            .fold(BigInt::zero(), |a, b| a + b)
+    }
+
+    /// Hilbert polynomial, agreeing with the Hilbert function at large enough arguments.
+    pub fn hilbert_polynomial(&self) -> Unipol<BigRational> {
+        let (h, d) = self.reduced();
+        if d == 0 {
+            return Unipol::zero();
+        }
+        let s: Unipol<BigRational> = Unipol::x();
+        let factorial = (1..d).fold(BigInt::one(), |a, j| a * BigInt::from(j));
+        h.iter()
+            .map(|(i, q)| {
+                // binomial(s - i + d - 1, d - 1), as a polynomial in s
+                let numer = (1..d).fold(Unipol::one(), |a, j| {
+                    let c = BigRational::from_integer(BigInt::from(j) - BigInt::from(i.0));
+                    a * (s.clone() + Unipol::from_coeffs(vec![c]))
+                });
+                Scalar(BigRational::new(q.clone(), factorial.clone())) * numer
+            })
+            .fold(Unipol::zero(), |a, b| a + b)
+    }
+}
+
+/// Numerator of the series of the quotient by the ideal generated by the exponent vectors `gens`,
+/// by the recursion `Q(I + (m)) = Q(I) - t^(deg m) Q(I : m)`.
+fn numerator(gens: Vec<Vec<usize>>) -> Unipol<BigInt> {
+    let mut gens = minimise(gens);
+    let deg = |m: &[usize]| m.iter().sum::<usize>();
+    let t_pow = |d: usize| {
+        let mut cs = vec![BigInt::zero(); d + 1];
+        cs[d] = BigInt::one();
+        Unipol::from_coeffs(cs)
+    };
+    if gens.iter().any(|m| deg(m) == 0) {
+        return Unipol::zero();
+    }
+    let coprime = (0..gens.len()).all(|i| {
+        (0..i).all(|j| {
+            gens[i]
+                .iter()
+                .zip(&gens[j])
+                .all(|(a, b)| *a == 0 || *b == 0)
+        })
+    });
+    if coprime {
+        return gens
+            .iter()
+            .fold(Unipol::one(), |q, m| q * (Unipol::one() - t_pow(deg(m))));
+    }
+    let m = gens.pop().unwrap();
+    let colon = gens
+        .iter()
+        .map(|g| {
+            g.iter()
+                .zip(&m)
+                .map(|(a, b)| (*a).saturating_sub(*b))
+                .collect()
+        })
+        .collect();
+    numerator(gens) - t_pow(deg(&m)) * numerator(colon)
+}
+
+/// Minimal generators, dropping the multiples of other generators, duplicates included.
+fn minimise(mut gens: Vec<Vec<usize>>) -> Vec<Vec<usize>> {
+    gens.sort_by_key(|m| m.iter().sum::<usize>());
+    let mut minimal: Vec<Vec<usize>> = Vec::with_capacity(gens.len());
+    for m in gens {
+        if minimal.iter().all(|n| n.iter().zip(&m).any(|(a, b)| a > b)) {
+            minimal.push(m);
+        }
+    }
+    minimal
+}
+
+fn binomial(n: usize, k: usize) -> BigInt {
+    (0..k).fold(BigInt::one(), |a, i| {
+        a * BigInt::from(n - i) / BigInt::from(i + 1)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monomial::*;
+    use crate::polynomial::groebner::buchberger;
+    use crate::polynomial::Ordpol;
+    use num_rational::Rational;
+
+    fn poly(cs: &[isize]) -> Unipol<BigInt> {
+        Unipol::from_coeffs(cs.iter().map(|c| BigInt::from(*c)).collect())
+    }
+
+    #[test]
+    fn twisted_cubic() {
+        let w: &Ordpol<Rational, Grevlex4> = &Ordpol::var(grevlex4::W);
+        let x: &Ordpol<Rational, Grevlex4> = &Ordpol::var(grevlex4::X);
+        let y: &Ordpol<Rational, Grevlex4> = &Ordpol::var(grevlex4::Y);
+        let z: &Ordpol<Rational, Grevlex4> = &Ordpol::var(grevlex4::Z);
+        let gb = buchberger(vec![w * y - x * x, x * z - y * y, w * z - x * y]);
+        let hs = HilbertSeries::from_groebner_basis(&gb);
+        assert_eq!(hs.variables(), 4);
+        assert_eq!(hs.numerator(), &poly(&[1, 0, -3, 2]));
+        assert_eq!(hs.reduced(), (poly(&[1, 2]), 2));
+        assert_eq!(hs.dimension(), Some(2));
+        assert_eq!(hs.degree(), BigInt::from(3));
+
+        let hp = hs.hilbert_polynomial();
+        let q = |n: isize| BigRational::from_integer(BigInt::from(n));
+        assert_eq!(hp, Unipol::from_coeffs(vec![q(1), q(3)]));
+        for s in 0..10 {
+            assert_eq!(hs.hilbert_function(s), BigInt::from(3 * s + 1));
+        }
+    }
+
+    #[test]
+    fn zero_dimensional() {
+        let hs = HilbertSeries::from_monomials(&[pow(Grevlex2::X, 2), pow(Grevlex2::Y, 3)]);
+        assert_eq!(hs.numerator(), &poly(&[1, 0, -1, -1, 0, 1]));
+        assert_eq!(hs.reduced(), (poly(&[1, 2, 2, 1]), 0));
+        assert_eq!(hs.dimension(), Some(0));
+        assert_eq!(hs.degree(), BigInt::from(6));
+        assert!(hs.hilbert_polynomial().is_zero());
+        let values: Vec<BigInt> = (0..6).map(|s| hs.hilbert_function(s)).collect();
+        assert_eq!(
+            values,
+            [1, 2, 2, 1, 0, 0]
+                .iter()
+                .map(|n| BigInt::from(*n))
+                .collect::<Vec<_>>()
+        );
+
+        // The affine ideal of the two intersection points of a circle and a line.
+        let x: &Ordpol<Rational, Grevlex2> = &Ordpol::var(grevlex2::X);
+        let y: &Ordpol<Rational, Grevlex2> = &Ordpol::var(grevlex2::Y);
+        let gb = buchberger(vec![x * x + y * y - Ordpol::one(), x - y]);
+        let hs = HilbertSeries::from_groebner_basis(&gb);
+        assert_eq!((hs.dimension(), hs.degree()), (Some(0), BigInt::from(2)));
+    }
+
+    #[test]
+    fn trivial_ideals() {
+        let hs = HilbertSeries::from_monomials::<Grevlex3>(&[]);
+        assert_eq!(hs.numerator(), &Unipol::one());
+        assert_eq!((hs.dimension(), hs.degree()), (Some(3), BigInt::one()));
+        assert_eq!(hs.hilbert_function(4), BigInt::from(15));
+
+        let hs = HilbertSeries::from_monomials(&[Grevlex3::X, Grevlex3::one()]);
+        assert!(hs.numerator().is_zero());
+        assert_eq!((hs.dimension(), hs.degree()), (None, BigInt::zero()));
+    }
+
+    #[test]
+    fn non_coprime_generators() {
+        // (xy, xz, yz): three coordinate lines, of dimension 1 and degree 3.
+        let hs = HilbertSeries::from_monomials(&[
+            Grevlex3::X * Grevlex3::Y,
+            Grevlex3::X * Grevlex3::Z,
+            Grevlex3::Y * Grevlex3::Z,
+        ]);
+        assert_eq!(hs.reduced(), (poly(&[1, 2]), 1));
+        assert_eq!((hs.dimension(), hs.degree()), (Some(1), BigInt::from(3)));
+
+        // Duplicates and multiples do not change the ideal.
+        let redundant = HilbertSeries::from_monomials(&[
+            Grevlex3::Y * Grevlex3::Z,
+            Grevlex3::X * Grevlex3::Y,
+            Grevlex3::X * Grevlex3::Y * Grevlex3::Z,
+            Grevlex3::X * Grevlex3::Z,
+            Grevlex3::X * Grevlex3::Y,
+        ]);
+        assert_eq!(redundant.numerator(), hs.numerator());
+    }
+}
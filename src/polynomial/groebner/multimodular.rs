@@ -0,0 +1,265 @@
+use crate::monomial::Monomial;
+use crate::polynomial::groebner::{f4, interreduce, is_groebner_basis_of};
+use crate::polynomial::{Ordpol, Polynomial};
+use crate::ring::*;
+use num::bigint::{BigInt, ToBigInt};
+use num_integer::Integer;
+use num_rational::Ratio;
+use num_traits::*;
+use std::collections::BTreeMap;
+use std::error;
+use std::fmt;
+
+/// Gröbner basis over Q, as reconstructed from its images.
+type Candidate<I, X> = Vec<Ordpol<Ratio<I>, X>>;
+
+/// Number of primes after which `modular_groebner_basis` gives up.
+pub(crate) const MAX_PRIMES: usize = 1000;
+
+/// Error returned by `modular_groebner_basis` when no verified candidate is found
+/// with `MAX_PRIMES` primes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoConvergence;
+
+impl fmt::Display for NoConvergence {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("modular Gröbner basis computation did not converge")
+    }
+}
+
+impl error::Error for NoConvergence {}
+
+/// Reduced Gröbner basis over Q computed by the multi-modular method.
+///
+/// The generators are made integral and their reduced bases are computed modulo
+/// word-sized primes by F4. Images are grouped by their leading monomials,
+/// and the majority group is taken to consist of lucky primes; the others are discarded.
+/// The coefficients are lifted by Chinese remaindering and rational reconstruction
+/// until the reconstruction stabilises. The candidate is then verified over Q:
+/// it must be a Gröbner basis into which every generator reduces to zero,
+/// and otherwise more primes are used. This proves that the candidate generates
+/// an ideal containing the input one. The reverse inclusion is not checked,
+/// since it would need a Gröbner basis over Q; it rests on the majority group
+/// consisting of lucky primes, whose images are those of the basis over Q.
+///
+/// Fails if no verified candidate is found with `MAX_PRIMES` primes,
+/// e.g. when the coefficients of the result do not fit in `I`.
+pub fn modular_groebner_basis<I, X>(
+    ideal: Vec<Ordpol<Ratio<I>, X>>,
+) -> Result<Vec<Ordpol<Ratio<I>, X>>, NoConvergence>
+where
+    I: NumAssign + Ring + Integer + ToBigInt + FromBigInt,
+    X: Monomial,
+{
+    modular_groebner_basis_with_primes(ideal, word_primes().take(MAX_PRIMES)).map(|(gb, _)| gb)
+}
+
+/// The primes below 2^31, in decreasing order.
+pub(crate) fn word_primes() -> impl Iterator<Item = u64> {
+    (2..1u64 << 31).rev().filter(|p| is_prime(*p))
+}
+
+/// Same as `modular_groebner_basis`, but lifting with the given primes,
+/// and also reporting how many candidates failed verification.
+pub(crate) fn modular_groebner_basis_with_primes<I, X>(
+    ideal: Vec<Ordpol<Ratio<I>, X>>,
+    primes: impl Iterator<Item = u64>,
+) -> Result<(Candidate<I, X>, usize), NoConvergence>
+where
+    I: NumAssign + Ring + Integer + ToBigInt + FromBigInt,
+    X: Monomial,
+{
+    let ideal: Vec<_> = ideal.into_iter().filter(|f| !f.is_zero()).collect();
+    if ideal.is_empty() {
+        return Ok((Vec::new(), 0));
+    }
+    let integral: Vec<BTreeMap<X, BigInt>> = ideal.iter().map(clear_denominators).collect();
+
+    let mut groups: Vec<Group<X, Candidate<I, X>>> = Vec::new();
+    let mut rejected = 0;
+    let primes = primes.filter(|p| {
+        // Primes dividing a leading coefficient are unlucky for sure.
+        let p = BigInt::from(*p);
+        integral
+            .iter()
+            .all(|f| !f.values().next_back().unwrap().is_multiple_of(&p))
+    });
+    for p in primes {
+        let image = image_basis(&integral, p);
+        let lms: Vec<X> = image
+            .iter()
+            .map(|g| g.keys().next_back().unwrap().clone())
+            .collect();
+        let i = match groups.iter().position(|g| g.lms == lms) {
+            Some(i) => {
+                groups[i].combine(&image, p);
+                i
+            }
+            None => {
+                groups.push(Group::new(lms, image, p));
+                groups.len() - 1
+            }
+        };
+        if groups.iter().any(|g| g.primes > groups[i].primes) {
+            continue;
+        }
+
+        let group = &mut groups[i];
+        let candidate = reconstruct(group);
+        let stable = candidate.is_some() && candidate == group.candidate;
+        group.candidate = candidate;
+        if stable {
+            let gb = group.candidate.take().unwrap();
+            if is_groebner_basis_of(&gb, &ideal) {
+                return Ok((gb, rejected));
+            }
+            rejected += 1;
+        }
+    }
+    Err(NoConvergence)
+}
+
+/// Images modulo a set of primes sharing the same leading monomials,
+/// combined into residues modulo the product of the primes,
+/// together with the last reconstructed candidate.
+pub(crate) struct Group<X, C> {
+    pub(crate) lms: Vec<X>,
+    pub(crate) primes: usize,
+    pub(crate) modulus: BigInt,
+    pub(crate) residues: Vec<BTreeMap<X, BigInt>>,
+    pub(crate) candidate: Option<C>,
+}
+
+impl<X: Monomial, C> Group<X, C> {
+    pub(crate) fn new(lms: Vec<X>, image: Vec<BTreeMap<X, u64>>, p: u64) -> Group<X, C> {
+        let residues = image
+            .into_iter()
+            .map(|g| g.into_iter().map(|(m, c)| (m, BigInt::from(c))).collect())
+            .collect();
+        Group {
+            lms,
+            primes: 1,
+            modulus: BigInt::from(p),
+            residues,
+            candidate: None,
+        }
+    }
+
+    /// Chinese remaindering with the image modulo `p`.
+    pub(crate) fn combine(&mut self, image: &[BTreeMap<X, u64>], p: u64) {
+        let m_inv = RuntimeModulus::with(p, || {
+            Fp::<RuntimeModulus>::new(residue(&self.modulus, p))
+                .inverse()
+                .unwrap()
+                .value()
+        });
+        for (acc, g) in self.residues.iter_mut().zip(image.iter()) {
+            let monomials: Vec<X> = acc.keys().chain(g.keys()).cloned().collect();
+            for m in monomials {
+                let a = acc.get(&m).cloned().unwrap_or_else(BigInt::zero);
+                let b = g.get(&m).cloned().unwrap_or(0);
+                // x = a + M * ((b - a) / M mod p)
+                let t = mul_mod(sub_mod(b, residue(&a, p), p), m_inv, p);
+                acc.insert(m, a + &self.modulus * BigInt::from(t));
+            }
+        }
+        self.modulus *= BigInt::from(p);
+        self.primes += 1;
+    }
+}
+
+/// Rational reconstruction of every coefficient, if possible.
+fn reconstruct<I, X, C>(group: &Group<X, C>) -> Option<Candidate<I, X>>
+where
+    I: NumAssign + Ring + Integer + FromBigInt,
+    X: Monomial,
+{
+    group
+        .residues
+        .iter()
+        .map(|g| {
+            let terms = g
+                .iter()
+                .map(|(m, a)| {
+                    let (n, d) = rational_reconstruction(a, &group.modulus)?;
+                    let c = Ratio::new(I::from_bigint(&n)?, I::from_bigint(&d)?);
+                    Some((m.clone(), c))
+                })
+                .collect::<Option<BTreeMap<_, _>>>()?;
+            Some(Ordpol::from_terms(terms))
+        })
+        .collect()
+}
+
+/// Reduced Gröbner basis of the integral generators modulo `p`, as maps to canonical residues.
+fn image_basis<X: Monomial>(integral: &[BTreeMap<X, BigInt>], p: u64) -> Vec<BTreeMap<X, u64>> {
+    RuntimeModulus::with(p, || {
+        let gens: Vec<Ordpol<Fp<RuntimeModulus>, X>> = integral
+            .iter()
+            .map(|f| {
+                Ordpol::from_terms(
+                    f.iter()
+                        .map(|(m, c)| (m.clone(), Fp::new(residue(c, p))))
+                        .collect(),
+                )
+            })
+            .collect();
+        interreduce(f4(gens))
+            .iter()
+            .map(|g| g.terms().into_iter().map(|(m, c)| (m, c.value())).collect())
+            .collect()
+    })
+}
+
+/// Multiplies `f` by the lcm of its denominators.
+fn clear_denominators<I, X>(f: &Ordpol<Ratio<I>, X>) -> BTreeMap<X, BigInt>
+where
+    I: NumAssign + Ring + Integer + ToBigInt,
+    X: Monomial,
+{
+    let big = |n: &I| n.to_bigint().unwrap();
+    let terms = f.terms();
+    let lcm = terms
+        .values()
+        .fold(BigInt::one(), |l, c| l.lcm(&big(c.denom())));
+    terms
+        .into_iter()
+        .map(|(m, c)| (m, big(c.numer()) * (&lcm / big(c.denom()))))
+        .collect()
+}
+
+/// Finds `n / d` congruent to `a` modulo `m`, with `|n|, d <= sqrt(m / 2)`.
+pub fn rational_reconstruction(a: &BigInt, m: &BigInt) -> Option<(BigInt, BigInt)> {
+    let bound = (m / BigInt::from(2)).sqrt();
+    let (mut r0, mut r1) = (m.clone(), a.mod_floor(m));
+    let (mut t0, mut t1) = (BigInt::zero(), BigInt::one());
+    while r1 > bound {
+        let q = &r0 / &r1;
+        let r = &r0 - &q * &r1;
+        let t = &t0 - &q * &t1;
+        r0 = r1;
+        r1 = r;
+        t0 = t1;
+        t1 = t;
+    }
+    if t1.is_zero() || t1.abs() > bound || !r1.gcd(&t1).is_one() {
+        return None;
+    }
+    if t1.is_negative() {
+        Some((-r1, -t1))
+    } else {
+        Some((r1, t1))
+    }
+}
+
+pub(crate) fn residue(n: &BigInt, p: u64) -> u64 {
+    n.mod_floor(&BigInt::from(p)).to_u64().unwrap()
+}
+
+fn sub_mod(a: u64, b: u64, p: u64) -> u64 {
+    (a + p - b) % p
+}
+
+fn mul_mod(a: u64, b: u64, p: u64) -> u64 {
+    (a as u128 * b as u128 % p as u128) as u64
+}
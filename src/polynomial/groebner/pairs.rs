@@ -0,0 +1,82 @@
+//! Gebauer–Möller criteria for pruning critical pairs.
+//!
+//! Whenever an element is added to a basis with leading monomials `lms`
+//! (the new one being the last), `new_pairs` returns the pairs it forms with the previous elements
+//! that survive the M and F steps and the product criterion,
+//! and `is_chain_redundant` tells which of the pending pairs are discarded by the B_k step.
+
+use crate::monomial::Monomial;
+
+/// Number of critical pairs discarded by each criterion.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PairStatistics {
+    /// Pending pairs discarded by the chain criterion when a new element arrives (B_k step).
+    pub chain_old: usize,
+    /// New pairs whose lcm is properly divisible by that of another new pair (M step).
+    pub chain_new: usize,
+    /// New pairs sharing their lcm with another new pair (F step).
+    pub same_lcm: usize,
+    /// New pairs with coprime leading monomials (product criterion).
+    pub coprime: usize,
+    /// Pairs whose S-polynomials were actually reduced.
+    pub reduced: usize,
+}
+
+impl PairStatistics {
+    /// Total number of pairs discarded by the criteria.
+    pub fn discarded(&self) -> usize {
+        self.chain_old + self.chain_new + self.same_lcm + self.coprime
+    }
+}
+
+/// Pairs `(n, k, lcm)` between the new element `n = lms.len() - 1` and the previous ones,
+/// after the M and F steps and the product criterion.
+pub(crate) fn new_pairs<X: Monomial>(
+    lms: &[X],
+    stats: &mut PairStatistics,
+) -> Vec<(usize, usize, X)> {
+    let n = lms.len() - 1;
+    let t = &lms[n];
+    let cands: Vec<(usize, X)> = (0..n).map(|k| (k, lms[k].clone().lcm(t.clone()))).collect();
+
+    // M: drop pairs whose lcm is properly divisible by the lcm of another one.
+    let (cands, dropped): (Vec<_>, Vec<_>) = cands
+        .iter()
+        .cloned()
+        .partition(|(_, m)| cands.iter().all(|(_, l)| !(l.divides(m) && l != m)));
+    stats.chain_new += dropped.len();
+
+    // F: keep one pair for each lcm, none at all if one of them is coprime.
+    let mut pairs = Vec::new();
+    let mut seen: Vec<&X> = Vec::new();
+    for (k, m) in &cands {
+        if seen.contains(&m) {
+            continue;
+        }
+        seen.push(m);
+        let class: Vec<usize> = cands
+            .iter()
+            .filter(|(_, l)| l == m)
+            .map(|(k, _)| *k)
+            .collect();
+        stats.same_lcm += class.len() - 1;
+        if class.iter().any(|k| is_coprime(&lms[*k], t)) {
+            stats.coprime += 1;
+        } else {
+            pairs.push((n, *k, m.clone()));
+        }
+    }
+    pairs
+}
+
+/// B_k step: whether the pending pair `(i, j)` is redundant
+/// in presence of the new element `lms.len() - 1`.
+pub(crate) fn is_chain_redundant<X: Monomial>(lms: &[X], i: usize, j: usize) -> bool {
+    let t = lms.last().unwrap();
+    let lcm = lms[i].clone().lcm(lms[j].clone());
+    t.divides(&lcm) && lms[i].clone().lcm(t.clone()) != lcm && lms[j].clone().lcm(t.clone()) != lcm
+}
+
+pub(crate) fn is_coprime<X: Monomial>(m: &X, n: &X) -> bool {
+    m.clone().lcm(n.clone()) == m.clone() * n.clone()
+}
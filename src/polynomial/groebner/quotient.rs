@@ -0,0 +1,223 @@
+//! Quotient algebras of zero-dimensional ideals.
+//!
+//! Normal forms modulo a Gröbner basis identify `R/I` with the span of the standard monomials,
+//! so that multiplications become matrices whose eigenvalues are the coordinates of the solutions.
+
+use crate::monomial::Monomial;
+use crate::polynomial::groebner::fglm::is_zero_dimensional;
+use crate::polynomial::groebner::is_groebner_basis_of;
+use crate::polynomial::Polynomial;
+use crate::ring::*;
+use crate::scalar::*;
+use num_traits::*;
+use std::collections::BTreeSet;
+use std::ops::*;
+
+/// Quotient algebra `R/I` of a zero-dimensional ideal `I`, as a vector space of finite dimension
+/// with the basis of standard monomials, i.e. those not divisible by any leading monomial
+/// of the Gröbner basis.
+///
+/// Matrices are vectors of rows; the matrix of a linear map has the coordinates
+/// of the image of the `j`-th basis monomial as its `j`-th column.
+#[derive(Debug, Clone)]
+pub struct QuotientAlgebra<P: Polynomial>
+where
+    Scalar<P::Coeff>: Mul<P, Output = P>,
+{
+    gb: Vec<P>,
+    standard: Vec<P::Monomial>,
+}
+
+impl<P: Polynomial> QuotientAlgebra<P>
+where
+    Scalar<P::Coeff>: Mul<P, Output = P>,
+    P::Coeff: Field,
+{
+    /// The quotient by the ideal with the Gröbner basis `gb`, e.g. from `reduced_groebner_basis`;
+    /// `None` if `gb` is not a Gröbner basis or the ideal is not zero-dimensional.
+    pub fn new(gb: Vec<P>) -> Option<QuotientAlgebra<P>> {
+        let gb: Vec<P> = gb.into_iter().filter(|g| !g.is_zero()).collect();
+        if !is_zero_dimensional(&gb) || !is_groebner_basis_of(&gb, &[]) {
+            return None;
+        }
+        let lms: Vec<P::Monomial> = gb.iter().filter_map(Polynomial::lead_monom).collect();
+        let is_standard = |m: &P::Monomial| lms.iter().all(|l| !l.divides(m));
+        let mut standard = BTreeSet::new();
+        let mut queue = vec![P::Monomial::one()];
+        while let Some(m) = queue.pop() {
+            if !is_standard(&m) || !standard.insert(m.clone()) {
+                continue;
+            }
+            for v in P::Monomial::variables() {
+                queue.push(m.clone() * P::Monomial::var(v));
+            }
+        }
+        Some(QuotientAlgebra {
+            gb,
+            standard: standard.into_iter().collect(),
+        })
+    }
+
+    /// The dimension of `R/I`, i.e. the number of solutions counted with multiplicity.
+    pub fn dimension(&self) -> usize {
+        self.standard.len()
+    }
+
+    /// The standard monomials, in increasing order.
+    pub fn standard_monomials(&self) -> &[P::Monomial] {
+        &self.standard
+    }
+
+    /// The normal form of `f`, a combination of standard monomials.
+    pub fn normal_form(&self, f: P) -> P {
+        f.div_mod_polys(self.gb.clone()).1
+    }
+
+    /// Coordinates of the class of `f` in the basis of standard monomials.
+    pub fn coordinates(&self, f: &P) -> Vec<P::Coeff> {
+        let nf = self.normal_form(f.clone());
+        let terms = nf.terms();
+        self.standard
+            .iter()
+            .map(|m| terms.get(m).map_or(P::Coeff::zero(), |c| (*c).clone()))
+            .collect()
+    }
+
+    /// The normal form with the given coordinates, one per standard monomial.
+    pub fn from_coordinates(&self, coords: &[P::Coeff]) -> P {
+        assert_eq!(
+            coords.len(),
+            self.dimension(),
+            "wrong number of coordinates"
+        );
+        P::from_terms(
+            self.standard
+                .iter()
+                .cloned()
+                .zip(coords.iter().cloned())
+                .filter(|(_, c)| !c.is_zero())
+                .collect(),
+        )
+    }
+
+    /// Matrix of the multiplication by `f`.
+    pub fn multiplication_matrix(&self, f: &P) -> Vec<Vec<P::Coeff>> {
+        let columns: Vec<Vec<P::Coeff>> = self
+            .standard
+            .iter()
+            .map(|m| self.coordinates(&(f.clone() * P::from_monomial(m.clone()))))
+            .collect();
+        (0..self.dimension())
+            .map(|i| columns.iter().map(|col| col[i].clone()).collect())
+            .collect()
+    }
+
+    /// Matrices of the multiplications by each variable, following the order of `variables()`.
+    pub fn variable_matrices(&self) -> Vec<Vec<Vec<P::Coeff>>> {
+        P::Monomial::variables()
+            .into_iter()
+            .map(|v| self.multiplication_matrix(&P::var(v)))
+            .collect()
+    }
+
+    /// Trace of the multiplication by `f`, the sum of the values of `f` at the solutions
+    /// counted with multiplicity.
+    pub fn trace(&self, f: &P) -> P::Coeff {
+        self.standard
+            .iter()
+            .enumerate()
+            .map(|(i, m)| self.coordinates(&(f.clone() * P::from_monomial(m.clone())))[i].clone())
+            .fold(P::Coeff::zero(), |a, b| a + b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monomial::*;
+    use crate::polynomial::groebner::reduced_groebner_basis;
+    use crate::polynomial::Ordpol;
+    use num_rational::Rational;
+
+    type P = Ordpol<Rational, Grevlex2>;
+
+    fn product(a: &[Vec<Rational>], b: &[Vec<Rational>]) -> Vec<Vec<Rational>> {
+        (0..a.len())
+            .map(|i| {
+                (0..b[0].len())
+                    .map(|j| (0..b.len()).fold(Rational::zero(), |s, k| s + a[i][k] * b[k][j]))
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn four_points() {
+        // x^2 = 2, y^2 = 3: the four points (±√2, ±√3).
+        let x: &P = &Ordpol::var(grevlex2::X);
+        let y: &P = &Ordpol::var(grevlex2::Y);
+        let c = |n: isize| P::from_int(n);
+        let q =
+            QuotientAlgebra::new(reduced_groebner_basis(vec![x * x - c(2), y * y - c(3)])).unwrap();
+        assert_eq!(q.dimension(), 4);
+        assert_eq!(
+            q.standard_monomials(),
+            &[
+                Grevlex2::one(),
+                Grevlex2::Y,
+                Grevlex2::X,
+                Grevlex2::X * Grevlex2::Y
+            ]
+        );
+
+        let r = Rational::from_integer;
+        assert_eq!(
+            q.coordinates(&(x * x * x * y + y)),
+            vec![r(0), r(1), r(0), r(2)]
+        );
+        assert_eq!(
+            q.from_coordinates(&[r(0), r(1), r(0), r(2)]),
+            y + c(2) * x * y
+        );
+
+        let ms = q.variable_matrices();
+        assert_eq!(ms.len(), 2);
+        assert_eq!(product(&ms[0], &ms[1]), product(&ms[1], &ms[0]));
+        assert_eq!(product(&ms[0], &ms[0]), q.multiplication_matrix(&c(2)));
+        assert_eq!(ms[1][1][0], r(1));
+        assert_eq!(ms[1][0][1], r(3));
+
+        assert_eq!(q.trace(x), r(0));
+        assert_eq!(q.trace(&(x * x + y * y)), r(20));
+        assert_eq!(q.trace(&(x * y + c(1))), r(4));
+    }
+
+    #[test]
+    fn multiplicities() {
+        // (x^2, y - x): the origin, with multiplicity 2.
+        let x: &P = &Ordpol::var(grevlex2::X);
+        let y: &P = &Ordpol::var(grevlex2::Y);
+        let q = QuotientAlgebra::new(reduced_groebner_basis(vec![x * x, y - x.clone()])).unwrap();
+        assert_eq!(q.dimension(), 2);
+        assert_eq!(q.trace(&P::one()), Rational::from_integer(2));
+        assert_eq!(q.trace(&(x + y)), Rational::zero());
+
+        assert!(QuotientAlgebra::new(reduced_groebner_basis(vec![x * y])).is_none());
+        // Zero-dimensional, but y^2 - y is missing from the Gröbner basis.
+        assert!(
+            QuotientAlgebra::new(vec![x * x - y.clone(), x * y - x.clone(), y * y * y]).is_none()
+        );
+        let unit = QuotientAlgebra::new(vec![P::one()]).unwrap();
+        assert_eq!(unit.dimension(), 0);
+        assert!(unit.coordinates(x).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "wrong number of coordinates")]
+    fn coordinates_must_match_dimension() {
+        let x: &P = &Ordpol::var(grevlex2::X);
+        let y: &P = &Ordpol::var(grevlex2::Y);
+        let q = QuotientAlgebra::new(vec![x * x, y.clone()]).unwrap();
+        q.from_coordinates(&[Rational::one()]);
+    }
+}
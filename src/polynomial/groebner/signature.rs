@@ -5,7 +5,7 @@ use crate::ring::*;
 use crate::scalar::*;
 
 use num_traits::*;
-use std::cmp::Ordering;
+use std::cmp::{Ordering, Reverse};
 use std::collections::BinaryHeap;
 use std::iter;
 use std::ops::*;
@@ -33,7 +33,11 @@ where
     // Initialisation
     let mut gs: Vec<(P, Entry<Sig<P>, Vec<P>>)> = Vec::new();
     let n = ideal.len();
-    let mut ps: BinaryHeap<_> = (0..n).into_iter().map(|i| to_entry(basis(n, i))).collect();
+    // Vectors are processed in increasing order of signatures.
+    let mut ps: BinaryHeap<_> = (0..n)
+        .into_iter()
+        .map(|i| Reverse(to_entry(basis(n, i))))
+        .collect();
     let mut syzs: Vec<_> = (0..n)
         .into_iter()
         .flat_map(|i: usize| {
@@ -53,7 +57,7 @@ where
         .collect();
 
     // Main loop
-    while let Some(Entry(g_sig, g)) = ps.pop() {
+    while let Some(Reverse(Entry(g_sig, g))) = ps.pop() {
         let syz_vecs: Vec<_> = syzs.iter().cloned().map(move |a| a.0).collect();
         let go_next = std_crieterion(&g_sig, syz_vecs.as_slice());
         let go_next = go_next || gs.iter().cloned().any(|(_, Entry(s, _))| s == g_sig);
@@ -72,7 +76,7 @@ where
             for i in gs.iter().cloned().filter_map(|(phg, Entry(_, g))| {
                 regular_svector(ph.clone(), h.clone(), phg, g).map(to_entry)
             }) {
-                ps.push(i);
+                ps.push(Reverse(i));
             }
             gs.push((ph, to_entry(h)));
         }
@@ -179,15 +183,15 @@ where
     f.split_lead_term().0.and_then(move |(lm_f, _)| {
         g.split_lead_term().0.and_then(move |(lm_g, _)| {
             let l = lm_f.clone().lcm(lm_g.clone());
-            (l / lm_f).and_then(|lm_f| {
+            (l.clone() / lm_f).and_then(|lm_f| {
                 (l / lm_g).and_then(|lm_g| {
                     let vl: Vec<P> = f_vec
                         .into_iter()
-                        .map(|f| f * P::from_monomial(lm_f))
+                        .map(|f| f * P::from_monomial(lm_f.clone()))
                         .collect();
                     let vr: Vec<P> = g_vec
                         .into_iter()
-                        .map(|f| f * P::from_monomial(lm_g))
+                        .map(|f| f * P::from_monomial(lm_g.clone()))
                         .collect();
                     if signature(vl.clone()) == signature(vr.clone()) {
                         None
@@ -224,7 +228,7 @@ where
     P: Polynomial,
     Scalar<P::Coeff>: Mul<P, Output = P>,
 {
-    i.iter().any(|g| divs(s, g))
+    i.iter().any(|g| divs(g, s))
 }
 
 fn dot<P>(ideal: Vec<P>, g: Vec<P>) -> P
@@ -257,7 +261,7 @@ where
             .cloned()
             .filter_map(|(h, Entry(_, mut hi))| {
                 h.lead_term().and_then(|(lm_h, lc_h)| {
-                    (m / lm_h).and_then(|lm| {
+                    (m.clone() / lm_h).and_then(|lm| {
                         let fac = Scalar(c.clone() / lc_h.clone()) * P::from_monomial(lm);
                         for i in hi.iter_mut() {
                             *i *= fac.clone();
@@ -284,3 +288,30 @@ where
 
     (g, phi)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monomial::*;
+    use crate::polynomial::groebner::is_groebner_basis_of;
+    use crate::polynomial::Ordpol;
+    use num_rational::Rational;
+
+    #[test]
+    fn f5_is_groebner() {
+        // Processing signatures in decreasing order, or testing whether the signature
+        // divides a syzygy rather than the converse, used to lose generators here.
+        let x: &Ordpol<Rational, Grevlex3> = &Ordpol::var(grevlex3::X);
+        let y: &Ordpol<Rational, Grevlex3> = &Ordpol::var(grevlex3::Y);
+        let z: &Ordpol<Rational, Grevlex3> = &Ordpol::var(grevlex3::Z);
+        let one = &Ordpol::one();
+        let ideals = vec![
+            vec![x * x * y - one, x * x * x - y * y - x],
+            vec![x * x + y * y + z * z - one, x * y * z - one, x + y - z * z],
+            vec![x * y - z.clone(), y * z - x.clone(), z * x - y.clone()],
+        ];
+        for ideal in ideals {
+            assert!(is_groebner_basis_of(&f5(ideal.clone()), &ideal));
+        }
+    }
+}
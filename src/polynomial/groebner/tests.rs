@@ -0,0 +1,271 @@
+use super::*;
+use crate::polynomial::*;
+use num_rational::*;
+
+type Lex3Pol = Ordpol<Rational, Lex3>;
+type Grevlex3Pol = Ordpol<Rational, Grevlex3>;
+
+fn vars<X: Monomial>() -> Vec<Ordpol<Rational, X>> {
+    X::variables().into_iter().map(Ordpol::var).collect()
+}
+
+fn examples<X: Monomial>() -> Vec<Vec<Ordpol<Rational, X>>> {
+    let v = vars::<X>();
+    let (x, y, z) = (&v[0], &v[1], &v[2]);
+    let one = &Ordpol::one();
+    vec![
+        vec![x * x * y - one, x * x * x - y * y - x],
+        vec![x * x + y * y + z * z - one, x * y * z - one, x + y - z * z],
+        vec![x * y - z, y * z - x, z * x - y],
+        vec![x * x - y, x * x * x - x],
+    ]
+}
+
+#[test]
+fn reduced_lex_example() {
+    let v = vars::<Lex3>();
+    let (x, y) = (&v[0], &v[1]);
+    let gb = reduced_groebner_basis(vec![x * x - y, x * x * x - x]);
+    assert_eq!(gb, vec![y * y - y, x * y - x, x * x - y]);
+}
+
+#[test]
+fn buchberger_is_groebner() {
+    for ideal in examples::<Lex3>() {
+        assert!(is_groebner_basis_of(&buchberger(ideal.clone()), &ideal));
+    }
+    for ideal in examples::<Grevlex3>() {
+        assert!(is_groebner_basis_of(&buchberger(ideal.clone()), &ideal));
+    }
+}
+
+#[test]
+fn pair_criteria() {
+    for ideal in examples::<Grevlex3>() {
+        let (gb, stats) = buchberger_with_stats(sugar, ideal.clone());
+        assert!(is_groebner_basis_of(&gb, &[]));
+        assert_eq!(interreduce(gb), reduced_groebner_basis(ideal));
+        assert!(stats.reduced > 0);
+    }
+
+    // Pairwise coprime leading monomials: every pair is discarded.
+    let v = vars::<Grevlex3>();
+    let (x, y, z) = (&v[0], &v[1], &v[2]);
+    let ideal = vec![x * x - y, y * y * y - z, z * z + x];
+    let (gb, stats) = buchberger_with_stats(sugar, ideal.clone());
+    assert_eq!(gb, ideal);
+    assert_eq!(stats.coprime, 3);
+    assert_eq!(stats.reduced, 0);
+
+    // x*y*z is the lcm of both x*z and y*z, so the F step applies on insertion of y*z.
+    let ideal = vec![x * y * z - x, x * z - y, y * z - z];
+    let (gb, stats) = buchberger_with_stats(sugar, ideal.clone());
+    assert!(is_groebner_basis_of(&gb, &[]));
+    assert!(stats.same_lcm > 0);
+}
+
+#[test]
+fn f4_is_groebner() {
+    for ideal in examples::<Lex3>() {
+        assert!(is_groebner_basis_of(&f4(ideal.clone()), &ideal));
+    }
+    for ideal in examples::<Grevlex3>() {
+        assert!(is_groebner_basis_of(&f4(ideal.clone()), &ideal));
+    }
+    assert!(is_groebner_basis_of(
+        &f4(five_variable_system::<Grevlex5>()),
+        &[]
+    ));
+}
+
+#[test]
+fn reduced_basis_is_unique() {
+    for ideal in examples::<Lex3>() {
+        let gb: Vec<Lex3Pol> = reduced_groebner_basis(ideal.clone());
+        assert_eq!(gb, interreduce(f5(ideal.clone())));
+        assert_eq!(gb, interreduce(f4(ideal.clone())));
+        let mut rev = ideal;
+        rev.reverse();
+        assert_eq!(gb, reduced_groebner_basis(rev));
+        assert_eq!(gb, interreduce(gb.clone()));
+    }
+    for ideal in examples::<Grevlex3>() {
+        let gb: Vec<Grevlex3Pol> = reduced_groebner_basis(ideal.clone());
+        assert_eq!(gb, interreduce(f5(ideal.clone())));
+        assert_eq!(gb, interreduce(f4(ideal)));
+    }
+}
+
+#[test]
+fn implicitization() {
+    // (x, y) = (t^2, t^3)
+    let v = vars::<Elim1Grevlex3>();
+    let (t, x, y) = (&v[0], &v[1], &v[2]);
+    let elim = eliminate(vec![x - t * t, y - t * t * t], &[elim1grevlex3::X]);
+    assert_eq!(elim, vec![x * x * x - y * y]);
+
+    let v = vars::<Lex3>();
+    let (t, x, y) = (&v[0], &v[1], &v[2]);
+    let elim = eliminate(vec![x - t * t, y - t * t * t], &[lex3::X]);
+    assert_eq!(elim, vec![x * x * x - y * y]);
+}
+
+#[test]
+fn eliminate_two_variables() {
+    // (x, y) = (s + t, s t), eliminating s and t leaves nothing.
+    let v = vars::<Elim2Grevlex4>();
+    let (s, t, x, y) = (&v[0], &v[1], &v[2], &v[3]);
+    let elim = eliminate(
+        vec![x - s - t, y - s * t],
+        &[elim2grevlex4::W, elim2grevlex4::X],
+    );
+    assert!(elim.is_empty());
+
+    // (x, y) = (s^2, t^2), with s t = 1.
+    let one = &Ordpol::one();
+    let elim = eliminate(
+        vec![x - s * s, y - t * t, s * t - one],
+        &[elim2grevlex4::W, elim2grevlex4::X],
+    );
+    assert_eq!(elim, vec![x * y - one]);
+}
+
+#[test]
+fn fglm_grevlex_to_lex() {
+    let lexes = examples::<Lex3>();
+    for (ideal, lex) in examples::<Grevlex3>().into_iter().zip(lexes) {
+        let gb = reduced_groebner_basis(ideal);
+        let converted: Option<Vec<Lex3Pol>> = fglm(&gb);
+        if fglm::is_zero_dimensional(&gb) {
+            assert_eq!(converted, Some(reduced_groebner_basis(lex)));
+            assert_eq!(fglm(&gb), Some(gb));
+        } else {
+            assert_eq!(converted, None);
+        }
+    }
+}
+
+fn five_variable_system<X: Monomial>() -> Vec<Ordpol<Rational, X>> {
+    let v = vars::<X>();
+    let one = &Ordpol::one();
+    vec![
+        &v[0] * &v[0] - &v[1] - one,
+        &v[1] * &v[1] - &v[2] * &v[3],
+        &v[2] * &v[2] - &v[0] + &v[4],
+        &v[3] * &v[3] - &v[4] - one,
+        &v[4] * &v[4] - &v[0] * &v[1],
+    ]
+}
+
+#[test]
+fn fglm_five_variables() {
+    let gb = reduced_groebner_basis(five_variable_system::<Grevlex5>());
+    assert_eq!(gb, interreduce(f4(five_variable_system())));
+    let lex: Vec<Ordpol<Rational, Lex5>> = fglm(&gb).unwrap();
+    assert!(is_groebner_basis_of(&lex, &[]));
+    assert_eq!(lex, reduced_groebner_basis(five_variable_system()));
+}
+
+#[test]
+fn fglm_unit_ideal() {
+    let v = vars::<Grevlex3>();
+    let (x, y) = (&v[0], &v[1]);
+    let gb = reduced_groebner_basis(vec![x * y - Ordpol::one(), x.clone()]);
+    let lex: Vec<Lex3Pol> = fglm(&gb).unwrap();
+    assert_eq!(lex, vec![Ordpol::one()]);
+}
+
+fn katsura3<X: Monomial>() -> Vec<Ordpol<Rational, X>> {
+    let v = vars::<X>();
+    let (u0, u1, u2, u3) = (&v[0], &v[1], &v[2], &v[3]);
+    let two = &Ordpol::from_nat(2);
+    let one = &Ordpol::one();
+    vec![
+        u0 + two * u1 + two * u2 + two * u3 - one,
+        u0 * u0 + two * u1 * u1 + two * u2 * u2 + two * u3 * u3 - u0,
+        two * u0 * u1 + two * u1 * u2 + two * u2 * u3 - u1,
+        two * u0 * u2 + u1 * u1 + two * u1 * u3 - u2,
+    ]
+}
+
+fn cyclic4<X: Monomial>() -> Vec<Ordpol<Rational, X>> {
+    let v = vars::<X>();
+    let (w, x, y, z) = (&v[0], &v[1], &v[2], &v[3]);
+    vec![
+        w + x + y + z,
+        w * x + x * y + y * z + z * w,
+        w * x * y + x * y * z + y * z * w + z * w * x,
+        w * x * y * z - Ordpol::one(),
+    ]
+}
+
+#[test]
+fn modular_matches_rational() {
+    for ideal in examples::<Grevlex3>() {
+        assert_eq!(
+            modular_groebner_basis(ideal.clone()).unwrap(),
+            reduced_groebner_basis(ideal)
+        );
+    }
+    for ideal in examples::<Lex3>() {
+        assert_eq!(
+            modular_groebner_basis(ideal.clone()).unwrap(),
+            reduced_groebner_basis(ideal)
+        );
+    }
+    assert_eq!(
+        modular_groebner_basis(katsura3::<Grevlex4>()).unwrap(),
+        reduced_groebner_basis(katsura3())
+    );
+    assert_eq!(
+        modular_groebner_basis(cyclic4::<Grevlex4>()).unwrap(),
+        reduced_groebner_basis(cyclic4())
+    );
+    let empty: Vec<Grevlex3Pol> = modular_groebner_basis(vec![Ordpol::zero()]).unwrap();
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn rational_reconstruction_bounds() {
+    use num::bigint::BigInt;
+    use num_integer::Integer;
+    let m = BigInt::from(1_000_000_007);
+    // -22/7 modulo m
+    let inv7 = BigInt::from(7).modpow(&(&m - 2i32), &m);
+    let a = (BigInt::from(-22) * inv7).mod_floor(&m);
+    assert_eq!(
+        multimodular::rational_reconstruction(&a, &m),
+        Some((BigInt::from(-22), BigInt::from(7)))
+    );
+    assert_eq!(
+        multimodular::rational_reconstruction(&BigInt::from(5), &m),
+        Some((BigInt::from(5), BigInt::from(1)))
+    );
+}
+
+#[test]
+fn big_rational_coefficients() {
+    type Pol = Ordpol<BigRational, Lex3>;
+    let f: Pol = "(x - 10000000000)^2".parse().unwrap();
+    assert_eq!(f.to_string(), "x^2 - 20000000000*x + 100000000000000000000");
+
+    let ideal: Vec<Pol> = vec![
+        "x^3 - 10000000000*y".parse().unwrap(),
+        "y^2 - 3*x*z + 7/5".parse().unwrap(),
+    ];
+    let gb = reduced_groebner_basis(ideal.clone());
+    assert!(is_groebner_basis_of(&gb, &[]));
+    assert_eq!(modular_groebner_basis(ideal), Ok(gb));
+}
+
+#[test]
+fn modular_rejects_wrong_lift() {
+    // 1000/7 is congruent to -2 modulo both 13 and 39, so the lift is stable but wrong.
+    let ideal: Vec<Lex3Pol> = vec!["7*x - 1000".parse().unwrap()];
+    let primes = vec![13, 3, 101, 103, 107, 109];
+    let (gb, rejected) =
+        multimodular::modular_groebner_basis_with_primes(ideal.clone(), primes.into_iter())
+            .unwrap();
+    assert_eq!(rejected, 1);
+    assert_eq!(gb, reduced_groebner_basis(ideal));
+}
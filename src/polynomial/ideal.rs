@@ -0,0 +1,110 @@
+use crate::polynomial::groebner::reduced_groebner_basis;
+use crate::polynomial::*;
+
+/// Ideal of a polynomial ring, given by its generators.
+/// The reduced Gröbner basis is computed once, on construction,
+/// and used for every membership and comparison query.
+#[derive(Debug, Clone)]
+pub struct Ideal<P> {
+    generators: Vec<P>,
+    basis: Vec<P>,
+}
+
+impl<P: Polynomial> Ideal<P>
+where
+    Scalar<P::Coeff>: Mul<P, Output = P>,
+    P::Coeff: Field,
+{
+    pub fn new(generators: Vec<P>) -> Ideal<P> {
+        let basis = reduced_groebner_basis(generators.clone());
+        Ideal { generators, basis }
+    }
+
+    pub fn generators(&self) -> &[P] {
+        &self.generators
+    }
+
+    /// The reduced Gröbner basis, in increasing order of leading monomials.
+    pub fn groebner_basis(&self) -> &[P] {
+        &self.basis
+    }
+
+    /// The remainder of `f` by the Gröbner basis, which is unique for each residue class.
+    pub fn normal_form(&self, f: P) -> P {
+        f.div_mod_polys(self.basis.clone()).1
+    }
+
+    pub fn contains(&self, f: &P) -> bool {
+        self.normal_form(f.clone()).is_zero()
+    }
+
+    pub fn is_subset_of(&self, other: &Ideal<P>) -> bool {
+        self.basis.iter().all(|g| other.contains(g))
+    }
+
+    /// Whether the ideal is the whole ring, i.e. contains `1`.
+    pub fn is_whole(&self) -> bool {
+        self.basis
+            .iter()
+            .any(|g| g.lead_monom().is_some_and(|m| m.is_one()))
+    }
+}
+
+impl<P: Polynomial> From<Vec<P>> for Ideal<P>
+where
+    Scalar<P::Coeff>: Mul<P, Output = P>,
+    P::Coeff: Field,
+{
+    fn from(generators: Vec<P>) -> Ideal<P> {
+        Ideal::new(generators)
+    }
+}
+
+/// Ideals are compared as sets, via their reduced Gröbner bases.
+impl<P: Polynomial> PartialEq for Ideal<P>
+where
+    Scalar<P::Coeff>: Mul<P, Output = P>,
+{
+    fn eq(&self, other: &Ideal<P>) -> bool {
+        self.basis == other.basis
+    }
+}
+
+impl<P: Polynomial> Eq for Ideal<P> where Scalar<P::Coeff>: Mul<P, Output = P> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_rational::*;
+
+    type Pol = Ordpol<Rational, Grevlex3>;
+
+    fn ideal(gens: &[&str]) -> Ideal<Pol> {
+        Ideal::new(gens.iter().map(|s| s.parse().unwrap()).collect())
+    }
+
+    fn pol(s: &str) -> Pol {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn membership() {
+        let i = ideal(&["x^2*y - 1", "x^3 - y^2 - x"]);
+        assert!(i.contains(&pol("(x^2*y - 1) * (z + x) + y^3 * (x^3 - y^2 - x)")));
+        assert!(!i.contains(&pol("x - y")));
+        assert_eq!(i.normal_form(pol("x^2*y + z")), i.normal_form(pol("1 + z")));
+        assert!(!i.is_whole());
+        assert!(ideal(&["x*y - 1", "x"]).is_whole());
+    }
+
+    #[test]
+    fn inclusion_and_equality() {
+        let i = ideal(&["x^2 - y", "x^3 - x"]);
+        let j = ideal(&["x^2 - y", "x*y - x", "y^2 - y"]);
+        let k = ideal(&["x^2 - y"]);
+        assert_eq!(i, j);
+        assert!(k.is_subset_of(&i));
+        assert!(!i.is_subset_of(&k));
+        assert!(k != i);
+    }
+}
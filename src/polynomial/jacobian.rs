@@ -0,0 +1,169 @@
+//! Jacobian and Hessian matrices, and their minors.
+//!
+//! Matrices are vectors of rows, whose columns are indexed by `Monomial::variables()`.
+//! The singular locus of a variety of codimension `c` defined by `fs`, for instance,
+//! is defined by `fs` together with `minors(&jacobian(&fs), c)`.
+
+use crate::monomial::Monomial;
+use crate::polynomial::Polynomial;
+use crate::scalar::Scalar;
+use crate::util::combinations;
+use std::ops::Mul;
+use std::slice;
+
+/// Jacobian matrix of `fs`: the row `i` is the gradient of `fs[i]`.
+pub fn jacobian<P: Polynomial>(fs: &[P]) -> Vec<Vec<P>>
+where
+    Scalar<P::Coeff>: Mul<P, Output = P>,
+{
+    let vars = P::Monomial::variables();
+    fs.iter()
+        .map(|f| vars.iter().map(|v| f.derivative(*v)).collect())
+        .collect()
+}
+
+/// Hessian matrix of `f`, the Jacobian matrix of its gradient.
+pub fn hessian<P: Polynomial>(f: &P) -> Vec<Vec<P>>
+where
+    Scalar<P::Coeff>: Mul<P, Output = P>,
+{
+    jacobian(&jacobian(slice::from_ref(f)).remove(0))
+}
+
+/// All the `k`-by-`k` minors of `matrix`, in lexicographic order of the chosen rows and columns.
+pub fn minors<P: Polynomial>(matrix: &[Vec<P>], k: usize) -> Vec<P>
+where
+    Scalar<P::Coeff>: Mul<P, Output = P>,
+{
+    let cols = matrix.first().map_or(0, Vec::len);
+    let mut result = Vec::new();
+    for rows in combinations(matrix.len(), k) {
+        for cs in combinations(cols, k) {
+            let sub: Vec<Vec<P>> = rows
+                .iter()
+                .map(|i| cs.iter().map(|j| matrix[*i][*j].clone()).collect())
+                .collect();
+            result.push(determinant(&sub));
+        }
+    }
+    result
+}
+
+/// Determinant of a square matrix, by cofactor expansion along the first row.
+pub fn determinant<P: Polynomial>(matrix: &[Vec<P>]) -> P
+where
+    Scalar<P::Coeff>: Mul<P, Output = P>,
+{
+    if matrix.is_empty() {
+        return P::one();
+    }
+    let mut det = P::zero();
+    for (j, a) in matrix[0].iter().enumerate() {
+        if a.is_zero() {
+            continue;
+        }
+        let minor: Vec<Vec<P>> = matrix[1..]
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .enumerate()
+                    .filter(|(l, _)| *l != j)
+                    .map(|(_, b)| b.clone())
+                    .collect()
+            })
+            .collect();
+        let term = a.clone() * determinant(&minor);
+        if j % 2 == 0 {
+            det += term;
+        } else {
+            det -= term;
+        }
+    }
+    det
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polynomial::*;
+    use num_rational::Rational;
+
+    type P = Ordpol<Rational, Grevlex3>;
+
+    #[test]
+    fn derivatives() {
+        let x: &P = &Ordpol::var(grevlex3::X);
+        let y: &P = &Ordpol::var(grevlex3::Y);
+        let z: &P = &Ordpol::var(grevlex3::Z);
+        let c = |n: isize| P::from_int(n);
+        let f = x * x * y + c(3) * y * z - c(5);
+        assert_eq!(f.derivative(grevlex3::X), c(2) * x * y);
+        assert_eq!(f.derivative(grevlex3::Y), x * x + c(3) * z);
+        assert_eq!(c(7).derivative(grevlex3::Z), c(0));
+
+        let t: &Unipol<Rational> = &Unipol::x();
+        let u = t * t * t - Unipol::from_int(2) * t;
+        assert_eq!(
+            u.derivative(()),
+            Unipol::from_int(3) * t * t - Unipol::from_int(2)
+        );
+
+        assert_eq!(
+            jacobian(&[f.clone(), x * z]),
+            vec![
+                vec![c(2) * x * y, x * x + c(3) * z, c(3) * y],
+                vec![z.clone(), c(0), x.clone()],
+            ]
+        );
+        assert_eq!(
+            hessian(&f),
+            vec![
+                vec![c(2) * y, c(2) * x, c(0)],
+                vec![c(2) * x, c(0), c(3)],
+                vec![c(0), c(3), c(0)],
+            ]
+        );
+        assert_eq!(determinant(&hessian(&f)), -c(18) * y);
+    }
+
+    #[test]
+    fn singular_locus() {
+        // The Whitney umbrella x^2 - y^2 z is singular along the z-axis.
+        let x: &P = &Ordpol::var(grevlex3::X);
+        let y: &P = &Ordpol::var(grevlex3::Y);
+        let z: &P = &Ordpol::var(grevlex3::Z);
+        let f = x * x - y * y * z;
+        let mut gens = vec![f.clone()];
+        gens.extend(minors(&jacobian(&[f]), 1));
+        assert_eq!(Ideal::new(gens), Ideal::new(vec![x.clone(), y * y, y * z]));
+
+        // The twisted cubic is smooth: the 2-minors with the generators give the whole ring.
+        let gs = vec![x * x - y.clone(), x * y - z.clone()];
+        let mut gens = gs.clone();
+        gens.extend(minors(&jacobian(&gs), 2));
+        assert!(Ideal::new(gens).is_whole());
+    }
+
+    #[test]
+    fn square_free_part() {
+        let x: &P = &Ordpol::var(grevlex3::X);
+        let y: &P = &Ordpol::var(grevlex3::Y);
+        let g = x * y + P::one();
+        let f = g.clone().pow(3) * (x - y);
+        let d = jacobian(slice::from_ref(&f))
+            .remove(0)
+            .iter()
+            .fold(f.clone(), |d, df| d.gcd_domain(df));
+        assert_eq!(f.exact_div(&d).unwrap(), g * (x - y));
+    }
+
+    #[test]
+    fn minors_of_matrix() {
+        let c = |n: isize| P::from_int(n);
+        let m = vec![vec![c(1), c(2), c(3)], vec![c(4), c(5), c(6)]];
+        assert_eq!(minors(&m, 2), vec![c(-3), c(-6), c(-3)]);
+        assert_eq!(minors(&m, 1).len(), 6);
+        assert_eq!(minors(&m, 0), vec![c(1)]);
+        assert!(minors(&m, 3).is_empty());
+    }
+}
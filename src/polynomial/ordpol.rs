@@ -1,10 +1,11 @@
 use crate::monomial::*;
-use crate::polynomial::Polynomial;
+use crate::polynomial::{exact_quotient, Polynomial};
 use crate::ring::*;
 use crate::scalar::*;
 use num_traits::*;
 use std::collections::btree_map as btm;
 use std::collections::BTreeMap;
+use std::iter;
 use std::ops::*;
 
 #[derive(Eq, PartialEq, Debug, Clone)]
@@ -16,7 +17,7 @@ impl<R: Zero + AddAssign<R> + Clone, X: Monomial> Add for Ordpol<R, X> {
     type Output = Ordpol<R, X>;
     fn add(mut self, other: Ordpol<R, X>) -> Ordpol<R, X> {
         for (k, v) in other.0 {
-            let to_remove = match self.0.entry(k) {
+            let to_remove = match self.0.entry(k.clone()) {
                 btm::Entry::Vacant(e) => {
                     e.insert(v);
                     false
@@ -139,6 +140,14 @@ impl<R: Ring, X: Monomial> Polynomial for Ordpol<R, X> {
         Ordpol([(X::var(v), R::one())].iter().cloned().collect())
     }
 
+    fn from_monomial(monomial: X) -> Self {
+        Ordpol(iter::once((monomial, R::one())).collect())
+    }
+
+    fn from_terms(terms: BTreeMap<X, R>) -> Self {
+        Ordpol(terms.into_iter().filter(|(_, c)| !c.is_zero()).collect())
+    }
+
     fn lead_term(&self) -> Option<(X, &R)> {
         self.0.iter().next_back().map(|(k, v)| (k.clone(), v))
     }
@@ -157,6 +166,111 @@ impl<R: Ring, X: Monomial> Polynomial for Ordpol<R, X> {
 
 lift_nums_to_ref!(impl for Ordpol<R, X> where R: Ring, X: Monomial);
 
+impl<R: Ring, X: Monomial> Ordpol<R, X> {
+    /// Whether all the terms have the same total degree.
+    pub fn is_homogeneous(&self) -> bool {
+        let mut degs = self.0.keys().map(Monomial::total_deg);
+        match degs.next() {
+            None => true,
+            Some(d) => degs.all(|e| e == d),
+        }
+    }
+
+    /// Homogenization by the variable `h`: each term is multiplied by the power of `h`
+    /// raising its degree to the total degree of the polynomial.
+    /// Terms which then coincide, when `h` already occurs, are summed.
+    pub fn homogenize_with(&self, h: X::Var) -> Self {
+        let d = self.total_deg();
+        let h = X::var(h);
+        self.map_monomials(|m| m.clone() * pow(h.clone(), d - m.total_deg()))
+    }
+
+    /// Dehomogenization, substituting one for the variable `h`.
+    pub fn dehomogenize_with(&self, h: X::Var) -> Self {
+        self.partial_eval(|v| if v == h { Some(R::one()) } else { None })
+    }
+
+    /// Homogenization into the monomials with an extra homogenizing variable.
+    pub fn homogenize<Y: Homogenized<Base = X>>(&self) -> Ordpol<R, Y> {
+        let d = self.total_deg();
+        let h = Y::var(Y::homogenizing_var());
+        Ordpol(
+            self.0
+                .iter()
+                .map(|(m, c)| {
+                    let m = Y::from_base(m) * pow(h.clone(), d - m.total_deg());
+                    (m, c.clone())
+                })
+                .collect(),
+        )
+    }
+}
+
+impl<R: Ring, Y: Homogenized> Ordpol<R, Y> {
+    /// Dehomogenization, substituting one for the homogenizing variable.
+    pub fn dehomogenize(&self) -> Ordpol<R, Y::Base> {
+        self.map_monomials(Homogenized::to_base)
+    }
+}
+
+impl<R: Ring, X: Monomial> Ordpol<R, X> {
+    /// The same polynomial with monomials of type `Y`, whose variables correspond to those of `X`
+    /// by their positions in `variables()`; e.g. from `Grevlex3` to `Lex3`.
+    pub fn change_order<Y: Monomial>(&self) -> Ordpol<R, Y> {
+        let xs = X::variables();
+        let ys = Y::variables();
+        assert_eq!(xs.len(), ys.len(), "numbers of variables mismatch");
+        self.map_variables(|v| ys[xs.iter().position(|x| *x == v).unwrap()])
+    }
+
+    /// Substitutes the variable `rename(v)` for each variable `v`.
+    /// The map need not be injective, in which case terms may merge.
+    pub fn map_variables<Y, F>(&self, rename: F) -> Ordpol<R, Y>
+    where
+        Y: Monomial,
+        F: Fn(X::Var) -> Y::Var,
+    {
+        self.map_monomials(|m| {
+            let exps: Vec<_> = m
+                .exponents()
+                .into_iter()
+                .map(|(v, n)| (rename(v), n))
+                .collect();
+            Y::from_exponents(&exps)
+        })
+    }
+
+    /// Permutes the variables, sending the `i`-th variable of `variables()`
+    /// to the `perm[i]`-th one.
+    pub fn permute_variables(&self, perm: &[usize]) -> Self {
+        let xs = X::variables();
+        assert_eq!(perm.len(), xs.len(), "numbers of variables mismatch");
+        let mut seen = vec![false; xs.len()];
+        for i in perm {
+            assert!(
+                *i < xs.len() && !std::mem::replace(&mut seen[*i], true),
+                "{:?} is not a permutation",
+                perm
+            );
+        }
+        self.map_variables(|v| xs[perm[xs.iter().position(|x| *x == v).unwrap()]])
+    }
+
+    fn map_monomials<Y: Monomial, F: Fn(&X) -> Y>(&self, f: F) -> Ordpol<R, Y> {
+        let mut terms: BTreeMap<Y, R> = BTreeMap::new();
+        for (m, c) in &self.0 {
+            *terms.entry(f(m)).or_insert_with(R::zero) += c.clone();
+        }
+        Ordpol::from_terms(terms)
+    }
+}
+
+impl<R: IntegralDomain, X: Monomial> IntegralDomain for Ordpol<R, X> {
+    fn exact_div(&self, other: &Self) -> Option<Self> {
+        exact_quotient(self, other)
+    }
+}
+
 impl<K: Field, X: Monomial> Div for Ordpol<K, X> {
     type Output = Ordpol<K, X>;
     #[inline]
@@ -178,3 +292,108 @@ derive_assign_with!(impl DivAssign, div_assign as Div, div for Ordpol<K, X> wher
 derive_assign_with!(impl RemAssign, rem_assign as Rem, rem for Ordpol<K, X> where K: Field, X: Monomial);
 lift_binop_to_ref!(impl Div, div for Ordpol<K, X> where K: Field, X: Monomial);
 lift_binop_to_ref!(impl Rem, rem for Ordpol<K, X> where K: Field, X: Monomial);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polynomial::groebner::{interreduce, reduced_groebner_basis};
+    use num_rational::Rational;
+
+    type P = Ordpol<Rational, Grevlex3>;
+
+    #[test]
+    fn homogenization() {
+        let x: &P = &Ordpol::var(grevlex3::X);
+        let y: &P = &Ordpol::var(grevlex3::Y);
+        let z: &P = &Ordpol::var(grevlex3::Z);
+        let f = x * x * y - y * z + x - P::from_int(2);
+        assert!(!f.is_homogeneous());
+
+        let g: Ordpol<Rational, Grevlex3H> = f.homogenize();
+        let xh: &Ordpol<_, Grevlex3H> = &Ordpol::var(grevlex3h::X);
+        let yh: &Ordpol<_, Grevlex3H> = &Ordpol::var(grevlex3h::Y);
+        let zh: &Ordpol<_, Grevlex3H> = &Ordpol::var(grevlex3h::Z);
+        let h: &Ordpol<_, Grevlex3H> = &Ordpol::var(grevlex3h::H);
+        assert_eq!(
+            g,
+            xh * xh * yh - yh * zh * h + xh * h * h - Ordpol::from_int(2) * h * h * h
+        );
+        assert!(g.is_homogeneous());
+        assert_eq!(g.dehomogenize(), f);
+        assert_eq!(P::zero().homogenize::<Grevlex3H>(), Ordpol::zero());
+
+        let g = (x * x - y).homogenize_with(grevlex3::Z);
+        assert_eq!(g, x * x - y * z);
+        assert_eq!(g.dehomogenize_with(grevlex3::Z), x * x - y);
+        assert_eq!(
+            (z + P::one()).homogenize_with(grevlex3::Z),
+            P::from_int(2) * z
+        );
+        assert!((z - P::one()).homogenize_with(grevlex3::Z).is_zero());
+        assert_eq!(
+            Grevlex3H::from_base(&(Grevlex3::X * Grevlex3::Z)),
+            Grevlex3H::X * Grevlex3H::Z
+        );
+        assert_eq!((Grevlex3H::Y * Grevlex3H::H).to_base(), Grevlex3::Y);
+    }
+
+    #[test]
+    fn change_of_order() {
+        let x: &P = &Ordpol::var(grevlex3::X);
+        let y: &P = &Ordpol::var(grevlex3::Y);
+        let z: &P = &Ordpol::var(grevlex3::Z);
+        let f = x * z * z + y * y * y - x * y + P::from_int(3);
+        assert_eq!(f.lead_monom(), Some(pow(Grevlex3::Y, 3)));
+
+        let g: Ordpol<Rational, Lex3> = f.change_order();
+        assert_eq!(g.lead_monom(), Some(Lex3::X * Lex3::Y));
+        assert_eq!(g.change_order::<Grevlex3>(), f);
+
+        // x -> y -> z -> x
+        let h = f.permute_variables(&[1, 2, 0]);
+        assert_eq!(h, y * x * x + z * z * z - y * z + P::from_int(3));
+        assert_eq!(h.permute_variables(&[2, 0, 1]), f);
+
+        let u: Ordpol<Rational, Grevlex2> = f.map_variables(|v| match v {
+            grevlex3::Z => grevlex2::Y,
+            _ => grevlex2::X,
+        });
+        let (s, t): (&Ordpol<_, Grevlex2>, &Ordpol<_, Grevlex2>) =
+            (&Ordpol::var(grevlex2::X), &Ordpol::var(grevlex2::Y));
+        assert_eq!(u, s * t * t + s * s * s - s * s + Ordpol::from_int(3));
+    }
+
+    #[test]
+    #[should_panic(expected = "not a permutation")]
+    fn permutation_must_be_bijective() {
+        P::one().permute_variables(&[0, 0, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "not a permutation")]
+    fn permutation_must_be_in_range() {
+        P::one().permute_variables(&[0, 3, 1]);
+    }
+
+    #[test]
+    fn dehomogenized_basis() {
+        // The dehomogenization of a Gröbner basis of the homogenized generators
+        // with respect to grevlex, with the homogenizing variable last, is a Gröbner basis.
+        let x: &P = &Ordpol::var(grevlex3::X);
+        let y: &P = &Ordpol::var(grevlex3::Y);
+        let z: &P = &Ordpol::var(grevlex3::Z);
+        let one = &P::one();
+        let ideal = vec![
+            x * x + y * z - one,
+            x * y - z.clone(),
+            z * z * z - x.clone(),
+        ];
+        let homogeneous: Vec<Ordpol<Rational, Grevlex3H>> =
+            ideal.iter().map(Ordpol::homogenize).collect();
+        let gb: Vec<P> = reduced_groebner_basis(homogeneous)
+            .iter()
+            .map(Ordpol::dehomogenize)
+            .collect();
+        assert_eq!(interreduce(gb), reduced_groebner_basis(ideal));
+    }
+}
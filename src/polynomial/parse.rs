@@ -0,0 +1,389 @@
+use crate::monomial::*;
+use crate::polynomial::*;
+use std::error;
+use std::fmt;
+use std::ops::*;
+use std::str::FromStr;
+
+/// Error raised when reading a polynomial from text.
+/// `position` is the byte offset in the input at which the error was detected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub position: usize,
+    pub kind: ParseErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    UnexpectedChar(char),
+    UnexpectedEnd,
+    UnknownVariable(String),
+    InvalidExponent,
+    NonConstantDivisor,
+    DivisionByZero,
+    /// The quotient is not representable in the coefficient ring, e.g. `3/2` over the integers.
+    InexactDivision,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.kind {
+            ParseErrorKind::UnexpectedChar(c) => write!(f, "unexpected character {:?}", c)?,
+            ParseErrorKind::UnexpectedEnd => write!(f, "unexpected end of input")?,
+            ParseErrorKind::UnknownVariable(v) => write!(f, "unknown variable {:?}", v)?,
+            ParseErrorKind::InvalidExponent => write!(f, "invalid exponent")?,
+            ParseErrorKind::NonConstantDivisor => write!(f, "divisor must be a constant")?,
+            ParseErrorKind::DivisionByZero => write!(f, "division by zero")?,
+            ParseErrorKind::InexactDivision => {
+                write!(f, "quotient not representable in the coefficient ring")?
+            }
+        }
+        write!(f, " at position {}", self.position)
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// Reads a polynomial written in the usual notation, e.g. `"35*y^4 - 30*x*y^2 + 3/2*x^2"`.
+///
+/// Variables are resolved with `Monomial::var_named`.
+/// Multiplication may be implicit (`3x^2 (x + y)`), exponents are non-negative integers,
+/// and divisors must be nonzero constants dividing every coefficient exactly,
+/// so that e.g. `3/2` is rejected over the integers.
+pub fn parse_polynomial<P>(input: &str) -> Result<P, ParseError>
+where
+    P: Polynomial,
+    P::Coeff: IntegralDomain,
+    Scalar<P::Coeff>: Mul<P, Output = P>,
+{
+    let mut parser = Parser { input, pos: 0 };
+    let p = parser.expr()?;
+    parser.skip_whitespace();
+    match parser.peek() {
+        None => Ok(p),
+        Some(c) => Err(parser.error(ParseErrorKind::UnexpectedChar(c))),
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) {
+        if let Some(c) = self.peek() {
+            self.pos += c.len_utf8();
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+    }
+
+    fn error(&self, kind: ParseErrorKind) -> ParseError {
+        ParseError {
+            position: self.pos,
+            kind,
+        }
+    }
+
+    fn unexpected(&self) -> ParseError {
+        match self.peek() {
+            None => self.error(ParseErrorKind::UnexpectedEnd),
+            Some(c) => self.error(ParseErrorKind::UnexpectedChar(c)),
+        }
+    }
+
+    fn take_while<F: Fn(char) -> bool>(&mut self, pred: F) -> &'a str {
+        let start = self.pos;
+        while self.peek().is_some_and(&pred) {
+            self.bump();
+        }
+        &self.input[start..self.pos]
+    }
+
+    /// expr ::= term (('+' | '-') term)*
+    fn expr<P>(&mut self) -> Result<P, ParseError>
+    where
+        P: Polynomial,
+        P::Coeff: IntegralDomain,
+        Scalar<P::Coeff>: Mul<P, Output = P>,
+    {
+        let mut acc = self.term()?;
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some('+') => {
+                    self.bump();
+                    acc += self.term()?;
+                }
+                Some('-') => {
+                    self.bump();
+                    acc -= self.term()?;
+                }
+                _ => return Ok(acc),
+            }
+        }
+    }
+
+    /// term ::= factor (('*' | '/')? factor)*
+    fn term<P>(&mut self) -> Result<P, ParseError>
+    where
+        P: Polynomial,
+        P::Coeff: IntegralDomain,
+        Scalar<P::Coeff>: Mul<P, Output = P>,
+    {
+        let mut acc = self.factor()?;
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some('*') => {
+                    self.bump();
+                    acc *= self.factor()?;
+                }
+                Some('/') => {
+                    self.bump();
+                    self.skip_whitespace();
+                    let start = self.pos;
+                    let d: P = self.factor()?;
+                    let at = |kind| ParseError {
+                        position: start,
+                        kind,
+                    };
+                    let c = match d.lead_term() {
+                        None => return Err(at(ParseErrorKind::DivisionByZero)),
+                        Some((m, c)) if m.is_one() => c.clone(),
+                        Some(_) => return Err(at(ParseErrorKind::NonConstantDivisor)),
+                    };
+                    acc = divide_coeffs(&acc, &c)
+                        .ok_or_else(|| at(ParseErrorKind::InexactDivision))?;
+                }
+                Some(c) if c.is_alphanumeric() || c == '_' || c == '(' || c == '.' => {
+                    acc *= self.factor()?;
+                }
+                _ => return Ok(acc),
+            }
+        }
+    }
+
+    /// factor ::= ('+' | '-') factor | atom ('^' exponent)?
+    fn factor<P>(&mut self) -> Result<P, ParseError>
+    where
+        P: Polynomial,
+        P::Coeff: IntegralDomain,
+        Scalar<P::Coeff>: Mul<P, Output = P>,
+    {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('-') => {
+                self.bump();
+                return self.factor().map(Neg::neg);
+            }
+            Some('+') => {
+                self.bump();
+                return self.factor();
+            }
+            _ => {}
+        }
+        let base = self.atom()?;
+        self.skip_whitespace();
+        if self.peek() == Some('^') {
+            self.bump();
+            self.skip_whitespace();
+            let start = self.pos;
+            let digits = self.take_while(|c| c.is_ascii_digit());
+            if digits.is_empty() {
+                return Err(self.unexpected());
+            }
+            let n = digits.parse::<usize>().map_err(|_| ParseError {
+                position: start,
+                kind: ParseErrorKind::InvalidExponent,
+            })?;
+            Ok(base.pow(n))
+        } else {
+            Ok(base)
+        }
+    }
+
+    /// atom ::= number | variable | '(' expr ')'
+    fn atom<P>(&mut self) -> Result<P, ParseError>
+    where
+        P: Polynomial,
+        P::Coeff: IntegralDomain,
+        Scalar<P::Coeff>: Mul<P, Output = P>,
+    {
+        match self.peek() {
+            Some('(') => {
+                self.bump();
+                let p = self.expr()?;
+                self.skip_whitespace();
+                if self.peek() == Some(')') {
+                    self.bump();
+                    Ok(p)
+                } else {
+                    Err(self.unexpected())
+                }
+            }
+            Some(c) if c.is_ascii_digit() || c == '.' => {
+                let start = self.pos;
+                let int = self.take_while(|c| c.is_ascii_digit());
+                let frac = if self.peek() == Some('.') {
+                    self.bump();
+                    self.take_while(|c| c.is_ascii_digit())
+                } else {
+                    ""
+                };
+                if int.is_empty() && frac.is_empty() {
+                    self.pos = start;
+                    return Err(self.unexpected());
+                }
+                let ten = P::Coeff::from_nat(10);
+                let digits = int.chars().chain(frac.chars());
+                let n = digits.fold(P::Coeff::zero(), |acc, d| {
+                    acc * ten.clone() + P::Coeff::from_nat(d.to_digit(10).unwrap() as usize)
+                });
+                match n.exact_div(&ten.pow(frac.len())) {
+                    Some(c) => Ok(P::from_coeff(c)),
+                    None => Err(ParseError {
+                        position: start,
+                        kind: ParseErrorKind::InexactDivision,
+                    }),
+                }
+            }
+            Some(c) if c.is_alphabetic() || c == '_' => {
+                let start = self.pos;
+                let name = self.take_while(|c| c.is_alphanumeric() || c == '_');
+                match P::Monomial::var_named(name) {
+                    Some(v) => Ok(P::var(v)),
+                    None => Err(ParseError {
+                        position: start,
+                        kind: ParseErrorKind::UnknownVariable(name.to_string()),
+                    }),
+                }
+            }
+            _ => Err(self.unexpected()),
+        }
+    }
+}
+
+/// Divides every coefficient of `p` by `c`, if they are all multiples of it.
+fn divide_coeffs<P>(p: &P, c: &P::Coeff) -> Option<P>
+where
+    P: Polynomial,
+    P::Coeff: IntegralDomain,
+    Scalar<P::Coeff>: Mul<P, Output = P>,
+{
+    let terms = p
+        .terms()
+        .into_iter()
+        .map(|(m, a)| a.exact_div(c).map(|q| (m, q)))
+        .collect::<Option<_>>()?;
+    Some(P::from_terms(terms))
+}
+
+impl<R: IntegralDomain, X: Monomial> FromStr for Ordpol<R, X> {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        parse_polynomial(s)
+    }
+}
+
+impl<R: IntegralDomain> FromStr for Unipol<R> {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        parse_polynomial(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num::bigint::BigInt;
+    use num_rational::*;
+
+    type Pol = Ordpol<Rational, Grevlex5>;
+
+    #[test]
+    fn parse_ordpol() {
+        let x: &Pol = &Ordpol::var(grevlex5::X);
+        let y: &Pol = &Ordpol::var(grevlex5::Y);
+        let z: &Pol = &Ordpol::var(grevlex5::Z);
+        let f: Pol = "35*y^4 - 30*x*y^2 + 3/2*x^2".parse().unwrap();
+        let g = Pol::from_nat(35) * y.clone().pow(4) - Pol::from_nat(30) * x * y * y
+            + Scalar(Rational::new(3, 2)) * (x * x);
+        assert_eq!(f, g);
+
+        let f: Pol = "-2x(y + z)^2 / 4 + 0.5".parse().unwrap();
+        let g = Scalar(Rational::new(-1, 2)) * (x * (y + z) * (y + z))
+            + Pol::from_coeff(Rational::new(1, 2));
+        assert_eq!(f, g);
+    }
+
+    #[test]
+    fn parse_unipol() {
+        let x = &Unipol::<Rational>::x();
+        let f: Unipol<Rational> = "x^3 - 2*x + 1".parse().unwrap();
+        assert_eq!(f, x * x * x - Unipol::from_nat(2) * x + Unipol::one());
+    }
+
+    #[test]
+    fn parse_dyn() {
+        monomial_context!(struct Ctx = MonomialDescriptor::new(
+            vec!["x1", "x2", "alpha"],
+            MonomialOrder::Lex
+        ););
+        let f: Ordpol<Rational, DynMonomial<Ctx>> = "x1 * alpha - x2^2".parse().unwrap();
+        assert_eq!(f.lead_monom(), Some(DynMonomial::from_vec(vec![1, 0, 1])));
+    }
+
+    #[test]
+    fn parse_over_integers() {
+        type IntPol = Ordpol<BigInt, Grevlex5>;
+        let x: &IntPol = &Ordpol::var(grevlex5::X);
+        let y: &IntPol = &Ordpol::var(grevlex5::Y);
+        let f: IntPol = "3x^2 - (4y + 6)/2 + 1.0".parse().unwrap();
+        assert_eq!(
+            f,
+            IntPol::from_nat(3) * x * x - IntPol::from_nat(2) * y - IntPol::from_nat(2)
+        );
+
+        let err = |s: &str| s.parse::<IntPol>().unwrap_err();
+        assert_eq!(
+            err("x + 3/2"),
+            ParseError {
+                position: 6,
+                kind: ParseErrorKind::InexactDivision
+            }
+        );
+        assert_eq!(err("(2x + 1) / 2").position, 11);
+        assert_eq!(err("x - 0.5").position, 4);
+
+        let u: Unipol<isize> = "x^2 - 6/3".parse().unwrap();
+        assert_eq!(u, Unipol::from_coeffs(vec![-2, 0, 1]));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = |s: &str| s.parse::<Pol>().unwrap_err();
+        assert_eq!(
+            err("x + q"),
+            ParseError {
+                position: 4,
+                kind: ParseErrorKind::UnknownVariable("q".to_string())
+            }
+        );
+        assert_eq!(err("x / y").kind, ParseErrorKind::NonConstantDivisor);
+        assert_eq!(err("x / (1 - 1)").position, 4);
+        assert_eq!(err("x^").kind, ParseErrorKind::UnexpectedEnd);
+        assert_eq!(err("(x + y").kind, ParseErrorKind::UnexpectedEnd);
+        assert_eq!(err("x + y)").kind, ParseErrorKind::UnexpectedChar(')'));
+        assert_eq!(err("x + y)").position, 5);
+    }
+}
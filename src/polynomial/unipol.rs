@@ -1,5 +1,5 @@
 use crate::monomial::*;
-use crate::polynomial::Polynomial;
+use crate::polynomial::{exact_quotient, Polynomial};
 use crate::ring::*;
 use crate::scalar::*;
 use num_traits::*;
@@ -9,6 +9,13 @@ use std::ops::*;
 use std::slice;
 use std::vec;
 
+mod factor;
+pub use self::factor::Factors;
+mod hensel;
+mod real_roots;
+pub use self::real_roots::RootInterval;
+mod resultant;
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Unipol<R> {
     coeffs: Vec<R>,
@@ -23,6 +30,20 @@ impl<R: Zero> Unipol<R> {
     }
 }
 
+impl<R: Zero> Unipol<R> {
+    /// Polynomial with the given coefficients, the constant term first.
+    pub fn from_coeffs(coeffs: Vec<R>) -> Unipol<R> {
+        Unipol { coeffs }.normalise()
+    }
+}
+
+impl<R> Unipol<R> {
+    /// Degree of the polynomial, `None` for zero.
+    pub fn degree(&self) -> Option<usize> {
+        self.coeffs.len().checked_sub(1)
+    }
+}
+
 impl<R: One + Zero> Unipol<R> {
     pub fn x() -> Unipol<R> {
         Unipol {
@@ -220,10 +241,24 @@ impl<R: Ring> Polynomial for Unipol<R> {
 
     fn pop_lead_term(&mut self) -> Option<(Power, R)> {
         let l = self.coeffs.len();
-        match self.coeffs.pop() {
-            None => None,
-            Some(v) => Some((Power(l - 1), v)),
+        let v = self.coeffs.pop()?;
+        while self.coeffs.last().is_some_and(Zero::is_zero) {
+            self.coeffs.pop();
         }
+        Some((Power(l - 1), v))
+    }
+
+    fn derivative(&self, _: ()) -> Self {
+        Unipol {
+            coeffs: self
+                .coeffs
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(i, c)| R::from_nat(i) * c.clone())
+                .collect(),
+        }
+        .normalise()
     }
 }
 
@@ -244,6 +279,86 @@ impl<R: Field> Rem for Unipol<R> {
     }
 }
 
+impl<R: IntegralDomain> IntegralDomain for Unipol<R> {
+    fn exact_div(&self, other: &Self) -> Option<Self> {
+        exact_quotient(self, other)
+    }
+}
+
+impl<K: Field + IntegralDomain> GcdDomain for Unipol<K> {
+    /// Monic gcd; zero if both are zero.
+    fn gcd_domain(&self, other: &Self) -> Self {
+        euclid_gcd(self, other)
+    }
+
+    fn lcm_domain(&self, other: &Self) -> Self {
+        euclid_lcm(self, other)
+    }
+}
+
+impl<K: Field + IntegralDomain> EuclideanDomain for Unipol<K> {
+    fn div_rem_domain(&self, other: &Self) -> (Self, Self) {
+        self.clone().div_mod(other.clone())
+    }
+
+    /// The leading coefficient, so that normalised polynomials are monic.
+    fn unit_part(&self) -> Self {
+        match self.coeffs.last() {
+            None => Unipol::one(),
+            Some(c) => Unipol {
+                coeffs: vec![c.clone()],
+            },
+        }
+    }
+}
+
 lift_nums_to_ref!(impl for Unipol<R> where R: Ring);
 derive_assign_with!(impl DivAssign, div_assign as Div, div for Unipol<K> where K: Field);
 derive_assign_with!(impl RemAssign, rem_assign as Rem, rem for Unipol<K> where K: Field);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_rational::Rational;
+
+    type Q = Rational;
+
+    fn poly(cs: &[isize]) -> Unipol<Q> {
+        Unipol {
+            coeffs: cs.iter().map(|&c| Q::from_integer(c)).collect(),
+        }
+        .normalise()
+    }
+
+    #[test]
+    fn gcd_and_lcm() {
+        // (x - 1)(x + 2) and 2 (x - 1)(x - 3)
+        let f = poly(&[-2, 1, 1]);
+        let g = poly(&[6, -8, 2]);
+        assert_eq!(f.gcd_domain(&g), poly(&[-1, 1]));
+        assert_eq!(f.lcm_domain(&g), poly(&[6, -5, -2, 1]));
+        assert_eq!(f.gcd_domain(&Unipol::zero()), f);
+        assert_eq!(g.gcd_domain(&Unipol::zero()), poly(&[3, -4, 1]));
+        assert!(Unipol::<Q>::zero().gcd_domain(&Unipol::zero()).is_zero());
+        assert!(f.lcm_domain(&Unipol::zero()).is_zero());
+        assert_eq!(f.gcd_domain(&poly(&[1, 0, 1])), Unipol::one());
+    }
+
+    #[test]
+    fn bezout_cofactors() {
+        let f = poly(&[1, 0, 3, 1, 0, 2]);
+        let g = poly(&[-4, 0, 1, 3]);
+        for (a, b) in [(&f, &g), (&g, &f), (&f, &(f.clone() * g.clone()))] {
+            let (d, s, t) = a.xgcd(b);
+            assert_eq!(d, a.gcd_domain(b));
+            assert_eq!(s * a.clone() + t * b.clone(), d);
+        }
+    }
+
+    #[test]
+    fn pop_lead_term_normalises() {
+        let mut f = poly(&[1, 0, 0, 1]);
+        assert_eq!(f.pop_lead_term(), Some((Power(3), Q::one())));
+        assert_eq!(f.lead_term(), Some((Power(0), &Q::one())));
+    }
+}
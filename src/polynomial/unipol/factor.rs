@@ -0,0 +1,280 @@
+//! Factorization of univariate polynomials over prime fields.
+//!
+//! A polynomial is first split into square-free parts, each of which is split
+//! by distinct-degree factorization into products of irreducibles of the same degree,
+//! which are finally separated by the probabilistic Cantor–Zassenhaus algorithm.
+
+use crate::polynomial::{Polynomial, Unipol};
+use crate::ring::*;
+use crate::scalar::*;
+use num_integer::Integer;
+use num_traits::*;
+
+/// Irreducible factors with their multiplicities.
+pub type Factors<R> = Vec<(Unipol<R>, usize)>;
+
+impl<M: Modulus> Unipol<Fp<M>> {
+    /// Factorization into the leading coefficient and monic irreducible factors
+    /// with their multiplicities, sorted by degree and coefficients.
+    pub fn factor(&self) -> (Fp<M>, Factors<Fp<M>>) {
+        let lc = *self.coeffs.last().expect("factorization of zero");
+        let mut factors: Factors<Fp<M>> = Vec::new();
+        for (g, m) in self.square_free_decomposition() {
+            for (h, d) in g.distinct_degree_factorization() {
+                for u in h.equal_degree_factorization(d) {
+                    factors.push((u, m));
+                }
+            }
+        }
+        factors.sort_by_key(|(u, m)| {
+            (
+                u.degree(),
+                u.coeffs.iter().map(Fp::value).collect::<Vec<_>>(),
+                *m,
+            )
+        });
+        (lc, factors)
+    }
+
+    /// Square-free decomposition: pairwise coprime monic square-free polynomials `g`
+    /// with distinct multiplicities `m`, such that `self` is a constant times the product of `g^m`.
+    /// Sorted by multiplicity.
+    pub fn square_free_decomposition(&self) -> Vec<(Unipol<Fp<M>>, usize)> {
+        let mut result = Vec::new();
+        let f = monic(self.clone());
+        if f.degree().unwrap_or(0) == 0 {
+            return result;
+        }
+        let mut c = f.gcd_domain(&f.derivative(()));
+        let mut w = f / c.clone();
+        let mut i = 1;
+        while !w.is_one() {
+            let y = w.gcd_domain(&c);
+            let z = w / y.clone();
+            if !z.is_one() {
+                result.push((z, i));
+            }
+            c /= y.clone();
+            w = y;
+            i += 1;
+        }
+        if !c.is_one() {
+            // Here `c' = 0`, so that `c` is a `p`-th power.
+            let p = M::modulus() as usize;
+            let root = Unipol {
+                coeffs: c.coeffs.into_iter().step_by(p).collect(),
+            };
+            for (g, j) in root.square_free_decomposition() {
+                result.push((g, j * p));
+            }
+        }
+        result.sort_by_key(|(_, m)| *m);
+        result
+    }
+
+    /// Distinct-degree factorization of a monic square-free polynomial:
+    /// pairs `(g, d)` such that `g` is the product of all the irreducible factors of degree `d`.
+    pub fn distinct_degree_factorization(&self) -> Vec<(Unipol<Fp<M>>, usize)> {
+        let mut result = Vec::new();
+        let mut f = self.clone();
+        let x = Unipol::x();
+        let mut h = x.clone();
+        let mut d = 1;
+        while f.degree().unwrap_or(0) >= 2 * d {
+            h = pow_mod(&h, M::modulus(), &f);
+            let g = f.gcd_domain(&(h.clone() - x.clone()));
+            if !g.is_one() {
+                f /= g.clone();
+                h %= f.clone();
+                result.push((g, d));
+            }
+            d += 1;
+        }
+        if let Some(n) = f.degree().filter(|n| *n > 0) {
+            result.push((f, n));
+        }
+        result
+    }
+
+    /// Cantor–Zassenhaus splitting of a monic square-free polynomial
+    /// whose irreducible factors all have degree `d`.
+    pub fn equal_degree_factorization(&self, d: usize) -> Vec<Unipol<Fp<M>>> {
+        let n = self.degree().unwrap_or(0);
+        assert!(
+            d > 0 && Integer::is_multiple_of(&n, &d),
+            "degree {} is not a multiple of {}",
+            n,
+            d
+        );
+        let mut factors = vec![self.clone()];
+        let mut rng = XorShift::new(n as u64);
+        while factors.len() < n / d {
+            let h = Unipol::from_coeffs((0..n).map(|_| Fp::new(rng.next())).collect());
+            let g = splitting_poly(&h, d, self);
+            factors = factors
+                .into_iter()
+                .flat_map(|u| {
+                    if u.degree() == Some(d) {
+                        return vec![u];
+                    }
+                    let v = u.gcd_domain(&g);
+                    if v.is_one() || v == u {
+                        vec![u]
+                    } else {
+                        let w = u / v.clone();
+                        vec![v, w]
+                    }
+                })
+                .collect();
+        }
+        factors
+    }
+}
+
+/// A polynomial whose gcd with `f` is, for a random `h`, a proper factor of `f` with probability about 1/2:
+/// `h^((p^d - 1) / 2) - 1` for odd `p`, and the trace `h + h^2 + ... + h^(2^(d-1))` for `p = 2`.
+fn splitting_poly<M: Modulus>(h: &Unipol<Fp<M>>, d: usize, f: &Unipol<Fp<M>>) -> Unipol<Fp<M>> {
+    let p = M::modulus();
+    let mut power = h.clone() % f.clone();
+    let mut acc = power.clone();
+    for _ in 1..d {
+        power = pow_mod(&power, p, f);
+        if p == 2 {
+            acc += power.clone();
+        } else {
+            acc = (acc * power.clone()) % f.clone();
+        }
+    }
+    if p == 2 {
+        acc
+    } else {
+        // (p^d - 1) / 2 = (1 + p + ... + p^(d-1)) * (p - 1) / 2
+        pow_mod(&acc, (p - 1) / 2, f) - Unipol::one()
+    }
+}
+
+/// `g^e mod f` by repeated squaring.
+fn pow_mod<K: Field>(g: &Unipol<K>, mut e: u64, f: &Unipol<K>) -> Unipol<K> {
+    let mut base = g.clone() % f.clone();
+    let mut acc = Unipol::one() % f.clone();
+    while e > 0 {
+        if e & 1 == 1 {
+            acc = (acc * base.clone()) % f.clone();
+        }
+        base = (base.clone() * base) % f.clone();
+        e >>= 1;
+    }
+    acc
+}
+
+fn monic<K: Field>(f: Unipol<K>) -> Unipol<K> {
+    match f.coeffs.last() {
+        Some(c) => Scalar(c.clone().recip()) * f,
+        None => f,
+    }
+}
+
+/// Deterministic pseudo-random numbers for choosing splitting polynomials.
+struct XorShift(u64);
+
+impl XorShift {
+    fn new(seed: u64) -> XorShift {
+        XorShift(seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1)
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type F2 = GF<2>;
+    type F3 = GF<3>;
+    type F7 = GF<7>;
+    type Big = GF<9_223_372_036_854_775_783>;
+
+    fn poly<M: Modulus>(cs: &[i64]) -> Unipol<Fp<M>> {
+        Unipol::from_coeffs(cs.iter().map(|c| Fp::from_i64(*c)).collect())
+    }
+
+    fn expand<M: Modulus>(lc: Fp<M>, factors: &[(Unipol<Fp<M>>, usize)]) -> Unipol<Fp<M>> {
+        factors
+            .iter()
+            .fold(Unipol::from_coeffs(vec![lc]), |acc, (u, m)| {
+                acc * u.clone().pow(*m)
+            })
+    }
+
+    fn is_irreducible<M: Modulus>(u: &Unipol<Fp<M>>) -> bool {
+        let n = u.degree().unwrap();
+        u.square_free_decomposition() == vec![(u.clone(), 1)]
+            && u.distinct_degree_factorization() == vec![(u.clone(), n)]
+    }
+
+    fn check_factorization<M: Modulus>(f: &Unipol<Fp<M>>) {
+        let (lc, factors) = f.factor();
+        assert_eq!(&expand(lc, &factors), f);
+        for (u, _) in &factors {
+            assert!(u.coeffs.last().unwrap().is_one());
+            assert!(is_irreducible(u), "{:?} is reducible", u);
+        }
+    }
+
+    #[test]
+    fn factor_known() {
+        // 3 (x^2 + 1)^2 (x + 3)^3 (x^3 + x + 1) over GF(7)
+        let a = poly::<ConstModulus<7>>(&[1, 0, 1]);
+        let b = poly(&[3, 1]);
+        let c = poly(&[1, 1, 0, 1]);
+        let f = Scalar(F7::new(3)) * a.clone().pow(2) * b.clone().pow(3) * c.clone();
+        let expected = vec![(b, 3), (a, 2), (c, 1)];
+        assert_eq!(f.factor(), (F7::new(3), expected));
+    }
+
+    #[test]
+    fn linear_factors_of_fermat_polynomial() {
+        let mut cs = vec![0; 8];
+        cs[1] = -1;
+        cs[7] = 1;
+        let (_, factors) = poly::<ConstModulus<7>>(&cs).factor();
+        assert_eq!(factors.len(), 7);
+        assert!(factors
+            .iter()
+            .all(|(u, m)| u.degree() == Some(1) && *m == 1));
+    }
+
+    #[test]
+    fn square_free_in_positive_characteristic() {
+        // (x^3 + 2)^3 (x + 1) over GF(3), where x^3 + 2 = (x + 2)^3
+        let f = poly::<ConstModulus<3>>(&[2, 0, 0, 1]).pow(3) * poly(&[1, 1]);
+        assert_eq!(
+            f.square_free_decomposition(),
+            vec![(poly(&[1, 1]), 1), (poly(&[2, 1]), 9)]
+        );
+        assert_eq!(
+            f.factor(),
+            (F3::one(), vec![(poly(&[1, 1]), 1), (poly(&[2, 1]), 9)])
+        );
+    }
+
+    #[test]
+    fn pseudo_random_polynomials() {
+        let mut rng = XorShift::new(42);
+        for n in 1..24 {
+            let mut cs: Vec<i64> = (0..=n).map(|_| (rng.next() % 1000) as i64).collect();
+            cs[n] = 1;
+            check_factorization(&poly::<ConstModulus<2>>(&cs));
+            check_factorization(&poly::<ConstModulus<3>>(&cs).pow(2));
+            check_factorization(&poly::<ConstModulus<7>>(&cs));
+        }
+        let f: Unipol<Big> = poly(&[1, -2]) * poly(&[3, 0, 1]) * poly(&[-5, 1]).pow(2);
+        check_factorization(&f);
+        assert_eq!(F2::one(), poly::<ConstModulus<2>>(&[1, 1, 1]).factor().0);
+    }
+}
@@ -0,0 +1,414 @@
+//! Factorization of univariate polynomials over the integers and rationals.
+//!
+//! A primitive polynomial is split into square-free parts by Yun's algorithm.
+//! Each part is factored modulo a prime for which it stays square-free,
+//! the modular factors are lifted by quadratic Hensel lifting beyond the Mignotte bound,
+//! and the true factors are recovered by Zassenhaus' search over subsets of lifted factors.
+
+use crate::polynomial::unipol::Factors;
+use crate::polynomial::{Polynomial, Unipol};
+use crate::ring::*;
+use crate::scalar::*;
+use crate::util::combinations;
+use num::bigint::{BigInt, ToBigInt};
+use num_integer::Integer;
+use num_rational::Ratio;
+use num_traits::*;
+
+/// Number of admissible primes among which the one with the fewest modular factors is chosen.
+const PRIME_TRIALS: usize = 5;
+
+type Zpol = Unipol<BigInt>;
+
+impl Unipol<BigInt> {
+    /// Factorization into the content, with the sign of the leading coefficient,
+    /// and primitive irreducible factors with positive leading coefficients and their multiplicities,
+    /// sorted by degree and coefficients.
+    pub fn factor(&self) -> (BigInt, Factors<BigInt>) {
+        let lc = self.coeffs.last().expect("factorization of zero");
+        let mut content = self.content();
+        let mut f = self.primitive_part();
+        if lc.is_negative() {
+            content = -content;
+            f = -f;
+        }
+        let mut factors: Factors<BigInt> = Vec::new();
+        if f.degree() != Some(0) {
+            for (g, m) in square_free_decomposition(&f) {
+                for h in factor_square_free(&g) {
+                    factors.push((h, m));
+                }
+            }
+        }
+        factors
+            .sort_by(|(u, m), (v, n)| (u.degree(), &u.coeffs, m).cmp(&(v.degree(), &v.coeffs, n)));
+        (content, factors)
+    }
+}
+
+impl<I> Unipol<Ratio<I>>
+where
+    I: NumAssign + Ring + Integer + ToBigInt + FromBigInt,
+{
+    /// Factorization into the leading coefficient and monic irreducible factors
+    /// with their multiplicities, sorted by degree.
+    ///
+    /// Panics if a coefficient of a factor does not fit in `I`.
+    pub fn factor(&self) -> (Ratio<I>, Factors<Ratio<I>>) {
+        let lc = self.coeffs.last().expect("factorization of zero").clone();
+        let big = |n: &I| n.to_bigint().unwrap();
+        let den = self
+            .coeffs
+            .iter()
+            .fold(BigInt::one(), |l, c| l.lcm_domain(&big(c.denom())));
+        let f = Unipol::from_coeffs(
+            self.coeffs
+                .iter()
+                .map(|c| big(c.numer()) * (&den / big(c.denom())))
+                .collect(),
+        );
+        let small = |n: &BigInt| I::from_bigint(n).expect("coefficient of a factor does not fit");
+        let factors = f
+            .factor()
+            .1
+            .into_iter()
+            .map(|(g, m)| {
+                let lc = small(g.coeffs.last().unwrap());
+                let coeffs = g
+                    .coeffs
+                    .iter()
+                    .map(|c| Ratio::new(small(c), lc.clone()))
+                    .collect();
+                (Unipol::from_coeffs(coeffs), m)
+            })
+            .collect();
+        (lc, factors)
+    }
+}
+
+/// Yun's square-free decomposition of a primitive polynomial with positive leading coefficient.
+fn square_free_decomposition(f: &Zpol) -> Factors<BigInt> {
+    let mut result = Vec::new();
+    let df = f.derivative(());
+    let a = primitive_gcd(f, &df);
+    let mut b = f.exact_div(&a).unwrap();
+    let mut d = df.exact_div(&a).unwrap() - b.derivative(());
+    let mut i = 1;
+    while b.degree() != Some(0) {
+        let a = primitive_gcd(&b, &d);
+        b = b.exact_div(&a).unwrap();
+        d = d.exact_div(&a).unwrap() - b.derivative(());
+        if a.degree() != Some(0) {
+            result.push((a, i));
+        }
+        i += 1;
+    }
+    result
+}
+
+/// Gcd of the primitive parts, with positive leading coefficient.
+fn primitive_gcd(f: &Zpol, g: &Zpol) -> Zpol {
+    match f.primitive_part().subresultants(&g.primitive_part()).pop() {
+        Some(h) if h.degree() != Some(0) => positive(h.primitive_part()),
+        _ => Unipol::one(),
+    }
+}
+
+/// Irreducible factors of a square-free primitive polynomial with positive leading coefficient.
+fn factor_square_free(f: &Zpol) -> Vec<Zpol> {
+    let n = f.degree().unwrap();
+    if n == 1 {
+        return vec![f.clone()];
+    }
+    let (p, modular) = choose_prime(f);
+    if modular.len() == 1 {
+        return vec![f.clone()];
+    }
+
+    // Mignotte: a factor h of f has |h|_1 <= 2^n |f|_2 <= 2^n (n + 1) |f|_inf,
+    // and the candidates are h scaled to have the leading coefficient of f.
+    let norm = f.coeffs.iter().map(BigInt::abs).max().unwrap();
+    let lc = f.coeffs[n].clone();
+    let bound = num_traits::pow(BigInt::from(2), n + 1) * BigInt::from(n + 1) * norm * lc;
+    let mut steps = 0;
+    let mut modulus = BigInt::from(p);
+    while modulus <= bound {
+        modulus = &modulus * &modulus;
+        steps += 1;
+    }
+    let lifted = multifactor_lift(f, &modular, p, steps);
+    recombine(f, lifted, &modulus)
+}
+
+/// Admissible prime, i.e. not dividing the leading coefficient and keeping `f` square-free,
+/// with the monic factors of `f` modulo it.
+fn choose_prime(f: &Zpol) -> (u64, Vec<Zpol>) {
+    let n = f.degree().unwrap();
+    let mut best: Option<(u64, Vec<Zpol>)> = None;
+    let mut trials = 0;
+    for p in (3..).filter(|p| is_prime(*p)) {
+        let factors = RuntimeModulus::with(p, || {
+            let g = to_fp(f, p);
+            if g.degree() != Some(n) || !g.gcd_domain(&g.derivative(())).is_one() {
+                return None;
+            }
+            Some(
+                g.factor()
+                    .1
+                    .iter()
+                    .map(|(u, _)| from_fp(u))
+                    .collect::<Vec<_>>(),
+            )
+        });
+        if let Some(factors) = factors {
+            if best.as_ref().is_none_or(|(_, b)| factors.len() < b.len()) {
+                best = Some((p, factors));
+            }
+            trials += 1;
+            if trials == PRIME_TRIALS || best.as_ref().unwrap().1.len() == 1 {
+                return best.unwrap();
+            }
+        }
+    }
+    unreachable!()
+}
+
+/// Lifts the factorization `f = lc(f) * us` modulo `p`, with monic `us`,
+/// to monic factors modulo `p^(2^steps)`, by splitting the factors in two halves recursively.
+fn multifactor_lift(f: &Zpol, us: &[Zpol], p: u64, steps: usize) -> Vec<Zpol> {
+    if us.len() == 1 {
+        let m = num_traits::pow(BigInt::from(p), 1 << steps);
+        let lc = f.coeffs.last().unwrap();
+        let inv = lc.xgcd(&m).1;
+        return vec![reduce(Scalar(inv) * f.clone(), &m)];
+    }
+    let (left, right) = us.split_at(us.len() / 2);
+    let pb = BigInt::from(p);
+    let lc = f.coeffs.last().unwrap().clone();
+    let mut g = reduce(
+        left.iter()
+            .fold(Unipol::from_coeffs(vec![lc]), |a, b| a * b.clone()),
+        &pb,
+    );
+    let mut h = reduce(right.iter().fold(Unipol::one(), |a, b| a * b.clone()), &pb);
+    let (mut s, mut t) = RuntimeModulus::with(p, || {
+        let (_, s, t) = to_fp(&g, p).xgcd(&to_fp(&h, p));
+        (from_fp(&s), from_fp(&t))
+    });
+    let mut m = pb;
+    for _ in 0..steps {
+        let lifted = hensel_step(f, &g, &h, &s, &t, &m);
+        g = lifted.0;
+        h = lifted.1;
+        s = lifted.2;
+        t = lifted.3;
+        m = &m * &m;
+    }
+    let mut factors = multifactor_lift(&g, left, p, steps);
+    factors.extend(multifactor_lift(&h, right, p, steps));
+    factors
+}
+
+/// Quadratic Hensel step: from `f = g h` and `s g + t h = 1` modulo `m`, with monic `h`,
+/// `deg s < deg h` and `deg t < deg g`, computes the same modulo `m^2`.
+fn hensel_step(
+    f: &Zpol,
+    g: &Zpol,
+    h: &Zpol,
+    s: &Zpol,
+    t: &Zpol,
+    m: &BigInt,
+) -> (Zpol, Zpol, Zpol, Zpol) {
+    let m2 = m * m;
+    let e = reduce(f.clone() - g.clone() * h.clone(), &m2);
+    let (q, r) = (s.clone() * e.clone()).pseudo_div_mod(h);
+    let g1 = reduce(g.clone() + t.clone() * e + q * g.clone(), &m2);
+    let h1 = reduce(h.clone() + r, &m2);
+
+    let b = reduce(
+        s.clone() * g1.clone() + t.clone() * h1.clone() - Unipol::one(),
+        &m2,
+    );
+    let (c, d) = (s.clone() * b.clone()).pseudo_div_mod(&h1);
+    let s1 = reduce(s.clone() - d, &m2);
+    let t1 = reduce(t.clone() - t.clone() * b - c * g1.clone(), &m2);
+    (g1, h1, s1, t1)
+}
+
+/// Zassenhaus recombination: finds the subsets of the monic factors lifted modulo `m`
+/// whose product, scaled by the leading coefficient, gives a true factor of `f`.
+fn recombine(f: &Zpol, mut lifted: Vec<Zpol>, m: &BigInt) -> Vec<Zpol> {
+    let mut f = f.clone();
+    let mut result = Vec::new();
+    let mut size = 1;
+    'search: while 2 * size <= lifted.len() {
+        for subset in combinations(lifted.len(), size) {
+            let lc = f.coeffs.last().unwrap().clone();
+            let g = subset
+                .iter()
+                .fold(Unipol::from_coeffs(vec![lc]), |a, i| a * lifted[*i].clone());
+            let g = positive(reduce(g, m).primitive_part());
+            if let Some(h) = f.exact_div(&g) {
+                result.push(g);
+                f = h;
+                for i in subset.into_iter().rev() {
+                    lifted.remove(i);
+                }
+                continue 'search;
+            }
+        }
+        size += 1;
+    }
+    result.push(f);
+    result
+}
+
+/// Coefficients reduced into the symmetric range modulo `m`.
+fn reduce(f: Zpol, m: &BigInt) -> Zpol {
+    let half = m / BigInt::from(2);
+    Unipol::from_coeffs(
+        f.coeffs
+            .into_iter()
+            .map(|c| {
+                let r = c.mod_floor(m);
+                if r > half {
+                    r - m
+                } else {
+                    r
+                }
+            })
+            .collect(),
+    )
+}
+
+fn positive(f: Zpol) -> Zpol {
+    if f.coeffs.last().is_some_and(BigInt::is_negative) {
+        -f
+    } else {
+        f
+    }
+}
+
+fn to_fp(f: &Zpol, p: u64) -> Unipol<Fp<RuntimeModulus>> {
+    let p = BigInt::from(p);
+    Unipol::from_coeffs(
+        f.coeffs
+            .iter()
+            .map(|c| Fp::new(c.mod_floor(&p).to_u64().unwrap()))
+            .collect(),
+    )
+}
+
+fn from_fp(f: &Unipol<Fp<RuntimeModulus>>) -> Zpol {
+    Unipol::from_coeffs(f.coeffs.iter().map(|c| BigInt::from(c.value())).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_rational::Rational;
+
+    fn poly(cs: &[i64]) -> Zpol {
+        Unipol::from_coeffs(cs.iter().map(|c| BigInt::from(*c)).collect())
+    }
+
+    fn expand(c: BigInt, factors: &[(Zpol, usize)]) -> Zpol {
+        factors
+            .iter()
+            .fold(Unipol::from_coeffs(vec![c]), |a, (u, m)| {
+                a * u.clone().pow(*m)
+            })
+    }
+
+    #[test]
+    fn factor_known() {
+        // -6 (x^2 + 1) (x - 3)^2 (2x + 5)^3
+        let a = poly(&[1, 0, 1]);
+        let b = poly(&[-3, 1]);
+        let c = poly(&[5, 2]);
+        let f = Scalar(BigInt::from(-6)) * a.clone() * b.clone().pow(2) * c.clone().pow(3);
+        let expected = vec![(b, 2), (c, 3), (a, 1)];
+        assert_eq!(f.factor(), (BigInt::from(-6), expected));
+        assert_eq!(poly(&[4]).factor(), (BigInt::from(4), vec![]));
+    }
+
+    #[test]
+    fn irreducible_yet_split_modulo_every_prime() {
+        let f = poly(&[1, 0, 0, 0, 1]);
+        let g = poly(&[1, 0, -10, 0, 1]);
+        assert_eq!(f.factor().1, vec![(f.clone(), 1)]);
+        assert_eq!(g.factor().1, vec![(g.clone(), 1)]);
+        let h = f.clone() * g.clone() * poly(&[-1, 0, 1]);
+        let expected = vec![(poly(&[-1, 1]), 1), (poly(&[1, 1]), 1), (g, 1), (f, 1)];
+        assert_eq!(h.factor().1, expected);
+    }
+
+    #[test]
+    fn cyclotomic() {
+        let mut cs = vec![0; 13];
+        cs[0] = -1;
+        cs[12] = 1;
+        let (_, factors) = poly(&cs).factor();
+        let expected = vec![
+            poly(&[-1, 1]),
+            poly(&[1, 1]),
+            poly(&[1, -1, 1]),
+            poly(&[1, 0, 1]),
+            poly(&[1, 1, 1]),
+            poly(&[1, 0, -1, 0, 1]),
+        ];
+        assert_eq!(
+            factors,
+            expected.into_iter().map(|u| (u, 1)).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn large_coefficients() {
+        let a = poly(&[-987_654_321, 123_456_789, 0, 1]);
+        let b = poly(&[-1_000_000_000_007, 0, 3]);
+        let c = poly(&[2_147_483_647, -1]);
+        let f = a.clone() * b.clone() * c.clone() * c.clone() * a.clone();
+        let (content, factors) = f.factor();
+        assert_eq!(expand(content, &factors), f);
+        assert_eq!(factors, vec![(positive(c), 2), (b, 1), (a, 2)]);
+    }
+
+    #[test]
+    fn products_are_split() {
+        let mut seed = 12345u64;
+        let mut random_poly = |n: usize| {
+            let cs: Vec<i64> = (0..=n)
+                .map(|_| {
+                    seed = seed.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1);
+                    (seed >> 33) as i64 % 41 - 20
+                })
+                .collect();
+            poly(&cs)
+        };
+        for n in 2..6 {
+            let (a, b) = (random_poly(n), random_poly(7 - n));
+            if a.degree() != Some(n) || b.degree() != Some(7 - n) {
+                continue;
+            }
+            let f = a * b;
+            let (content, factors) = f.factor();
+            assert_eq!(expand(content, &factors), f);
+            assert!(factors.iter().map(|(_, m)| m).sum::<usize>() >= 2);
+        }
+    }
+
+    #[test]
+    fn rational_factorization() {
+        let q = |cs: &[(isize, isize)]| {
+            Unipol::from_coeffs(cs.iter().map(|(n, d)| Rational::new(*n, *d)).collect())
+        };
+        // (x/2 - 1/3) (x^2 + 1/5)^2
+        let f = q(&[(-1, 3), (1, 2)]) * q(&[(1, 5), (0, 1), (1, 1)]).pow(2);
+        let expected = vec![
+            (q(&[(-2, 3), (1, 1)]), 1),
+            (q(&[(1, 5), (0, 1), (1, 1)]), 2),
+        ];
+        assert_eq!(f.factor(), (Rational::new(1, 2), expected));
+    }
+}
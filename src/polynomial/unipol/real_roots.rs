@@ -0,0 +1,430 @@
+//! Isolation of the real roots of univariate polynomials with rational coefficients.
+//!
+//! Roots are isolated by bisection of a bounding interval, counting the roots in each part
+//! either exactly by Sturm's theorem or, faster, by Descartes' rule of signs after mapping
+//! the interval onto the positive reals (the Vincent–Collins–Akritas algorithm).
+//! All the polynomials here must be square-free.
+
+use crate::polynomial::{Polynomial, Unipol};
+use crate::ring::*;
+use num_integer::Integer;
+use num_rational::Ratio;
+use num_traits::*;
+use std::cmp::Ordering;
+
+/// Interval isolating a real root: the open interval `(lower, upper)` containing exactly one root,
+/// or the root itself when `lower == upper`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RootInterval<T> {
+    pub lower: T,
+    pub upper: T,
+}
+
+impl<T: Ring> RootInterval<T> {
+    /// Whether the root is known exactly, i.e. `lower == upper`.
+    pub fn is_exact(&self) -> bool {
+        self.lower == self.upper
+    }
+
+    pub fn width(&self) -> T {
+        self.upper.clone() - self.lower.clone()
+    }
+}
+
+impl<I> Unipol<Ratio<I>>
+where
+    I: NumAssign + Ring + Integer,
+{
+    /// Sturm sequence: `f`, `f'`, and then the negated remainders of the Euclidean algorithm.
+    pub fn sturm_sequence(&self) -> Vec<Unipol<Ratio<I>>> {
+        let mut seq = vec![self.clone(), self.derivative(())];
+        seq.retain(|f| !f.is_zero());
+        while seq.len() >= 2 {
+            let r = seq[seq.len() - 2].clone() % seq[seq.len() - 1].clone();
+            if r.is_zero() {
+                break;
+            }
+            seq.push(-r);
+        }
+        seq
+    }
+
+    /// Number of distinct real roots in the half-open interval `(a, b]`, by Sturm's theorem.
+    /// Panics if `a > b`.
+    pub fn sturm_count(&self, a: &Ratio<I>, b: &Ratio<I>) -> usize {
+        assert!(a <= b, "sturm_count needs a <= b");
+        let seq = self.sturm_sequence();
+        variations_at(&seq, a) - variations_at(&seq, b)
+    }
+
+    /// Isolating intervals of all the real roots, in increasing order, by Sturm sequences.
+    pub fn isolate_real_roots_sturm(&self) -> Vec<RootInterval<Ratio<I>>> {
+        let seq = self.sturm_sequence();
+        // Roots in the open interval; `V(a) - V(b)` counts those in `(a, b]`.
+        let count = |a: &Ratio<I>, b: &Ratio<I>| {
+            let on_b = horner(self, b).is_zero() as usize;
+            variations_at(&seq, a) - variations_at(&seq, b) - on_b
+        };
+        let mut roots = Vec::new();
+        if self.degree().unwrap_or(0) > 0 {
+            let b = root_bound(self);
+            bisect(self, -b.clone(), b, &count, &mut roots);
+        }
+        roots
+    }
+
+    /// Isolating intervals of all the real roots, in increasing order,
+    /// by the Vincent–Collins–Akritas algorithm.
+    ///
+    /// The polynomial is made integral, and its positive and negative roots are isolated
+    /// in `(0, 2^k)`, for `2^k` above the root bound, by bisection with integral Taylor shifts.
+    pub fn isolate_real_roots(&self) -> Vec<RootInterval<Ratio<I>>> {
+        let mut roots = Vec::new();
+        if self.degree().unwrap_or(0) == 0 {
+            return roots;
+        }
+        let den = self.coeffs.iter().fold(I::one(), |l, c| l.lcm(c.denom()));
+        let mut f: Vec<I> = self
+            .coeffs
+            .iter()
+            .map(|c| c.numer().clone() * (den.clone() / c.denom().clone()))
+            .collect();
+        if f[0].is_zero() {
+            roots.push(RootInterval {
+                lower: Ratio::zero(),
+                upper: Ratio::zero(),
+            });
+            let k = f.iter().take_while(|c| c.is_zero()).count();
+            f.drain(..k);
+        }
+
+        let bound = root_bound(self);
+        let two = I::from_nat(2);
+        let mut scale = I::one();
+        while Ratio::from_integer(scale.clone()) < bound {
+            scale *= two.clone();
+        }
+        let negated: Vec<I> = f
+            .iter()
+            .enumerate()
+            .map(|(i, c)| if i % 2 == 0 { c.clone() } else { -c.clone() })
+            .collect();
+        for (g, sign) in [(negated, -I::one()), (f, I::one())] {
+            // Roots of `g(scale * y)` in `(0, 1)`.
+            let mut power = I::one();
+            let g = g
+                .into_iter()
+                .map(|c| {
+                    let c = c * power.clone();
+                    power *= scale.clone();
+                    c
+                })
+                .collect();
+            let mut found: Vec<RootInterval<Ratio<I>>> = unit_interval_roots(g)
+                .into_iter()
+                .map(|(c, k, exact)| {
+                    let den = two.clone().pow(k);
+                    let lower = Ratio::new(sign.clone() * scale.clone() * c.clone(), den.clone());
+                    let upper = if exact {
+                        lower.clone()
+                    } else {
+                        Ratio::new(sign.clone() * scale.clone() * (c + I::one()), den)
+                    };
+                    if lower <= upper {
+                        RootInterval { lower, upper }
+                    } else {
+                        RootInterval {
+                            lower: upper,
+                            upper: lower,
+                        }
+                    }
+                })
+                .collect();
+            roots.append(&mut found);
+        }
+        roots.sort_by(|a, b| a.lower.cmp(&b.lower));
+        roots
+    }
+
+    /// Refines an isolating interval of a root by bisection, until its width is at most `width`.
+    pub fn refine_root(
+        &self,
+        interval: &RootInterval<Ratio<I>>,
+        width: &Ratio<I>,
+    ) -> RootInterval<Ratio<I>> {
+        let mut iv = interval.clone();
+        if iv.is_exact() {
+            return iv;
+        }
+        // The root is simple, so the sign changes across it;
+        // at a root endpoint, the sign just inside the interval is given by the derivative.
+        let df = self.derivative(());
+        let sign_right_of = |x: &Ratio<I>| match sign(&horner(self, x)) {
+            Ordering::Equal => sign(&horner(&df, x)),
+            s => s,
+        };
+        let lower_sign = sign_right_of(&iv.lower);
+        let two = Ratio::from_integer(I::from_nat(2));
+        while iv.width() > *width {
+            let m = (iv.lower.clone() + iv.upper.clone()) / two.clone();
+            let s = sign(&horner(self, &m));
+            if s == Ordering::Equal {
+                return RootInterval {
+                    lower: m.clone(),
+                    upper: m,
+                };
+            } else if s == lower_sign {
+                iv.lower = m;
+            } else {
+                iv.upper = m;
+            }
+        }
+        iv
+    }
+}
+
+/// Isolates the roots in the open interval `(a, b)` by bisection,
+/// given a function `count` bounding the number of roots in open intervals,
+/// which is exact when the bound is zero or one.
+fn bisect<I, F>(
+    f: &Unipol<Ratio<I>>,
+    a: Ratio<I>,
+    b: Ratio<I>,
+    count: &F,
+    roots: &mut Vec<RootInterval<Ratio<I>>>,
+) where
+    I: NumAssign + Ring + Integer,
+    F: Fn(&Ratio<I>, &Ratio<I>) -> usize,
+{
+    match count(&a, &b) {
+        0 => {}
+        1 => roots.push(RootInterval { lower: a, upper: b }),
+        _ => {
+            let m = (a.clone() + b.clone()) / Ratio::from_integer(I::from_nat(2));
+            bisect(f, a, m.clone(), count, roots);
+            if horner(f, &m).is_zero() {
+                roots.push(RootInterval {
+                    lower: m.clone(),
+                    upper: m.clone(),
+                });
+            }
+            bisect(f, m, b, count, roots);
+        }
+    }
+}
+
+/// Cauchy's bound: every root has absolute value less than `1 + max |c_i / c_n|`.
+fn root_bound<I>(f: &Unipol<Ratio<I>>) -> Ratio<I>
+where
+    I: NumAssign + Ring + Integer,
+{
+    let lc = f.coeffs.last().unwrap().clone();
+    let max = f
+        .coeffs
+        .iter()
+        .map(|c| abs(c.clone() / lc.clone()))
+        .max()
+        .unwrap();
+    Ratio::one() + max
+}
+
+/// Roots of the integral polynomial `f` in `(0, 1)`, with `f(0) != 0`, as triples `(c, k, exact)`:
+/// the root is `c / 2^k` if `exact`, and in `(c / 2^k, (c + 1) / 2^k)` otherwise.
+///
+/// The polynomial attached to the interval `(c / 2^k, (c + 1) / 2^k)` is `2^(kn) f((x + c) / 2^k)`,
+/// whose roots in `(0, 1)` are bounded by Descartes' rule of signs applied to `(x + 1)^n g(1 / (x + 1))`.
+fn unit_interval_roots<I>(f: Vec<I>) -> Vec<(I, usize, bool)>
+where
+    I: NumAssign + Ring + Integer,
+{
+    let two = I::from_nat(2);
+    let mut roots = Vec::new();
+    let mut todo = vec![(f, I::zero(), 0)];
+    while let Some((g, c, k)) = todo.pop() {
+        let mut rev = g.clone();
+        rev.reverse();
+        match variations(taylor_shift(rev).iter().map(sign)) {
+            0 => continue,
+            1 => {
+                roots.push((c, k, false));
+                continue;
+            }
+            _ => {}
+        }
+        // 2^n g(x / 2), and 2^n g((x + 1) / 2)
+        let n = g.len() - 1;
+        let left: Vec<I> = g
+            .into_iter()
+            .enumerate()
+            .map(|(i, a)| a * two.clone().pow(n - i))
+            .collect();
+        let mut right = taylor_shift(left.clone());
+        let c = c * two.clone();
+        if right[0].is_zero() {
+            roots.push((c.clone() + I::one(), k + 1, true));
+            right.remove(0);
+        }
+        todo.push((right, c.clone() + I::one(), k + 1));
+        todo.push((left, c, k + 1));
+    }
+    roots
+}
+
+/// Coefficients of `f(x + 1)`.
+fn taylor_shift<I: Ring>(mut f: Vec<I>) -> Vec<I> {
+    let n = f.len();
+    for i in 0..n {
+        for j in (i..n - 1).rev() {
+            let a = f[j + 1].clone();
+            f[j] += a;
+        }
+    }
+    f
+}
+
+/// Sign variations of the Sturm sequence at `x`.
+fn variations_at<I>(seq: &[Unipol<Ratio<I>>], x: &Ratio<I>) -> usize
+where
+    I: NumAssign + Ring + Integer,
+{
+    variations(seq.iter().map(|f| sign(&horner(f, x))))
+}
+
+/// Number of sign changes, ignoring zeros.
+fn variations<It: Iterator<Item = Ordering>>(signs: It) -> usize {
+    let mut last = Ordering::Equal;
+    let mut count = 0;
+    for s in signs.filter(|s| *s != Ordering::Equal) {
+        if last != Ordering::Equal && s != last {
+            count += 1;
+        }
+        last = s;
+    }
+    count
+}
+
+fn horner<R: Ring>(f: &Unipol<R>, x: &R) -> R {
+    f.coeffs
+        .iter()
+        .rev()
+        .fold(R::zero(), |acc, c| acc * x.clone() + c.clone())
+}
+
+fn sign<T: Zero + PartialOrd>(x: &T) -> Ordering {
+    x.partial_cmp(&T::zero()).unwrap()
+}
+
+fn abs<T: Ring + PartialOrd>(x: T) -> T {
+    if x < T::zero() {
+        -x
+    } else {
+        x
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_rational::BigRational;
+
+    type Q = BigRational;
+
+    fn q(n: i64, d: i64) -> Q {
+        Q::new(n.into(), d.into())
+    }
+
+    fn poly(cs: &[i64]) -> Unipol<Q> {
+        Unipol::from_coeffs(cs.iter().map(|c| q(*c, 1)).collect())
+    }
+
+    /// Checks that the intervals are sorted, disjoint and isolate `n` roots.
+    fn check_isolation(f: &Unipol<Q>, roots: &[RootInterval<Q>], n: usize) {
+        assert_eq!(roots.len(), n);
+        for iv in roots {
+            if iv.is_exact() {
+                assert!(horner(f, &iv.lower).is_zero());
+            } else {
+                assert!(iv.lower < iv.upper);
+                let on_upper = horner(f, &iv.upper).is_zero() as usize;
+                assert_eq!(f.sturm_count(&iv.lower, &iv.upper) - on_upper, 1);
+            }
+        }
+        for w in roots.windows(2) {
+            assert!(w[0].upper <= w[1].lower);
+        }
+    }
+
+    #[test]
+    fn sturm_counts() {
+        // (x^2 - 2) (x + 3)
+        let f = poly(&[-6, -2, 3, 1]);
+        assert_eq!(f.sturm_count(&q(-10, 1), &q(10, 1)), 3);
+        assert_eq!(f.sturm_count(&q(0, 1), &q(10, 1)), 1);
+        assert_eq!(f.sturm_count(&q(-3, 1), &q(0, 1)), 1);
+        assert_eq!(f.sturm_count(&q(-4, 1), &q(-3, 1)), 1);
+        assert_eq!(f.sturm_count(&q(1, 1), &q(1, 1)), 0);
+        assert_eq!(f.sturm_sequence().len(), 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "needs a <= b")]
+    fn sturm_count_of_reversed_interval() {
+        poly(&[-2, 0, 1]).sturm_count(&q(2, 1), &q(0, 1));
+    }
+
+    #[test]
+    fn isolate_mixed_roots() {
+        // (x - 1) (x + 2) (2x - 3) (x^2 - 2) (x^2 + 1)
+        let f =
+            poly(&[-1, 1]) * poly(&[2, 1]) * poly(&[-3, 2]) * poly(&[-2, 0, 1]) * poly(&[1, 0, 1]);
+        let vca = f.isolate_real_roots();
+        let sturm = f.isolate_real_roots_sturm();
+        check_isolation(&f, &vca, 5);
+        check_isolation(&f, &sturm, 5);
+        assert!(vca.iter().any(|iv| iv.is_exact() && iv.lower == q(1, 1)));
+    }
+
+    #[test]
+    fn no_real_roots() {
+        let f = poly(&[1, 0, 1]) * poly(&[3, 1, 1]);
+        assert!(f.isolate_real_roots().is_empty());
+        assert!(f.isolate_real_roots_sturm().is_empty());
+        assert!(poly(&[5]).isolate_real_roots().is_empty());
+    }
+
+    #[test]
+    fn close_roots() {
+        // (1000 x - 1) (1000 x - 2) (x - 1/999) (x - 1000)
+        let f = poly(&[-1, 1000]) * poly(&[-2, 1000]) * poly(&[-1, 999]) * poly(&[-1000, 1]);
+        check_isolation(&f, &f.isolate_real_roots(), 4);
+        check_isolation(&f, &f.isolate_real_roots_sturm(), 4);
+        let wilkinson = (1..=10).fold(poly(&[1]), |acc, k| acc * poly(&[-k, 1]));
+        check_isolation(&wilkinson, &wilkinson.isolate_real_roots(), 10);
+    }
+
+    #[test]
+    fn refine_to_width() {
+        let f = poly(&[-2, 0, 1]);
+        let roots = f.isolate_real_roots();
+        let width = q(1, 1_000_000_000);
+        for iv in &roots {
+            let fine = f.refine_root(iv, &width);
+            assert!(fine.width() <= width);
+            assert!(fine.lower >= iv.lower && fine.upper <= iv.upper);
+            let (lo, hi) = (horner(&f, &fine.lower), horner(&f, &fine.upper));
+            assert!(lo * hi < Q::zero());
+        }
+        let exact = RootInterval {
+            lower: q(1, 1),
+            upper: q(1, 1),
+        };
+        assert_eq!(poly(&[-1, 1]).refine_root(&exact, &width), exact);
+        // A root found exactly by refinement.
+        let g = poly(&[-1, 4]) * poly(&[1, 0, 1]);
+        let iv = RootInterval {
+            lower: q(0, 1),
+            upper: q(1, 1),
+        };
+        assert!(g.refine_root(&iv, &width).is_exact());
+    }
+}
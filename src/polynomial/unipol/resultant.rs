@@ -0,0 +1,255 @@
+//! Subresultant pseudo-remainder sequences, resultants and discriminants.
+//!
+//! Everything here is fraction-free: only exact divisions in the coefficient ring are performed,
+//! so that it works over integers, or polynomials in the other variables.
+
+use crate::polynomial::{Polynomial, Unipol};
+use crate::ring::*;
+use crate::scalar::*;
+use num_integer::Integer;
+use num_traits::*;
+use std::iter;
+use std::mem;
+
+impl<R: Ring> Unipol<R> {
+    /// Pseudo-division by a non-zero `g`: `(q, r)` with `lc(g)^e * self = q * g + r`
+    /// and `deg r < deg g`, where `e = max(deg self - deg g + 1, 0)`.
+    pub fn pseudo_div_mod(&self, g: &Unipol<R>) -> (Unipol<R>, Unipol<R>) {
+        let n = g.degree().expect("pseudo-division by zero");
+        let b = g.coeffs[n].clone();
+        let mut e = match self.degree() {
+            Some(m) if m >= n => m - n + 1,
+            _ => return (Unipol::zero(), self.clone()),
+        };
+        let mut q = Unipol::zero();
+        let mut r = self.clone();
+        while let Some(m) = r.degree().filter(|m| *m >= n) {
+            let t = monomial(r.coeffs[m].clone(), m - n);
+            q = Scalar(b.clone()) * q + t.clone();
+            r = Scalar(b.clone()) * r - t * g.clone();
+            e -= 1;
+        }
+        let c = b.pow(e);
+        (Scalar(c.clone()) * q, Scalar(c) * r)
+    }
+}
+
+impl<R: IntegralDomain> Unipol<R> {
+    /// Subresultant pseudo-remainder sequence of `self` and `other`,
+    /// the one of larger degree first, up to the last non-zero term.
+    ///
+    /// Each term is the pseudo-remainder of the previous two divided by a factor
+    /// keeping the coefficients small, so that the terms are subresultants of the inputs;
+    /// in particular, the last one is their gcd up to a factor in `R`.
+    pub fn subresultants(&self, other: &Unipol<R>) -> Vec<Unipol<R>> {
+        let (a, b) = if self.degree() >= other.degree() {
+            (self.clone(), other.clone())
+        } else {
+            (other.clone(), self.clone())
+        };
+        let mut prs: Vec<Unipol<R>> = vec![a, b];
+        prs.retain(|f| !f.is_zero());
+        if prs.len() < 2 {
+            return prs;
+        }
+
+        let mut d = prs[0].degree().unwrap() - prs[1].degree().unwrap();
+        let mut beta = if d.is_even() {
+            -R::one()
+        } else {
+            R::one()
+        };
+        let mut psi = -R::one();
+        loop {
+            let (r0, r1) = (&prs[prs.len() - 2], &prs[prs.len() - 1]);
+            let r = r0.pseudo_div_mod(r1).1.exact_div_coeffs(&beta);
+            if r.is_zero() {
+                return prs;
+            }
+            let gamma = r1.coeffs.last().unwrap().clone();
+            psi = if d == 0 {
+                psi
+            } else {
+                (-gamma.clone()).pow(d).exact_div(&psi.pow(d - 1)).unwrap()
+            };
+            d = r1.degree().unwrap() - r.degree().unwrap();
+            beta = -gamma * psi.clone().pow(d);
+            prs.push(r);
+        }
+    }
+
+    /// Resultant of `self` and `other`, i.e. the determinant of their Sylvester matrix;
+    /// zero if either of them is zero, one if both are non-zero constants.
+    pub fn resultant(&self, other: &Unipol<R>) -> R {
+        if self.is_zero() || other.is_zero() {
+            return R::zero();
+        }
+        let (mut a, mut b) = (self.clone(), other.clone());
+        let mut s = R::one();
+        if a.degree() < b.degree() {
+            mem::swap(&mut a, &mut b);
+            if a.degree().unwrap() % 2 == 1 && b.degree().unwrap() % 2 == 1 {
+                s = -s;
+            }
+        }
+
+        let mut g = R::one();
+        let mut h = R::one();
+        loop {
+            let da = a.degree().unwrap();
+            let db = b.degree().unwrap();
+            if db == 0 {
+                let lc = b.coeffs[0].clone();
+                return s * if da == 0 {
+                    h
+                } else {
+                    lc.pow(da).exact_div(&h.pow(da - 1)).unwrap()
+                };
+            }
+            if da % 2 == 1 && db % 2 == 1 {
+                s = -s;
+            }
+            let delta = da - db;
+            let r = a.pseudo_div_mod(&b).1;
+            let r = r.exact_div_coeffs(&(g * h.clone().pow(delta)));
+            if r.is_zero() {
+                return R::zero();
+            }
+            a = mem::replace(&mut b, r);
+            g = a.coeffs.last().unwrap().clone();
+            if delta > 0 {
+                h = g.clone().pow(delta).exact_div(&h.pow(delta - 1)).unwrap();
+            }
+        }
+    }
+
+    /// Discriminant `(-1)^(n(n-1)/2) res(f, f') / lc(f)` of a polynomial `f` of degree `n > 0`.
+    ///
+    /// Over rings of positive characteristic, this is only meaningful when `f'` has degree `n - 1`.
+    pub fn discriminant(&self) -> R {
+        let n = match self.degree() {
+            Some(n) if n > 0 => n,
+            _ => panic!("discriminant of a constant polynomial"),
+        };
+        let lc = self.coeffs[n].clone();
+        let d = self.resultant(&self.derivative(())).exact_div(&lc).unwrap();
+        if (n * (n - 1) / 2) % 2 == 0 {
+            d
+        } else {
+            -d
+        }
+    }
+
+    /// Divides every coefficient by `c`, which must divide them.
+    fn exact_div_coeffs(self, c: &R) -> Unipol<R> {
+        Unipol {
+            coeffs: self
+                .coeffs
+                .into_iter()
+                .map(|a| {
+                    a.exact_div(c)
+                        .expect("inexact division in subresultant sequence")
+                })
+                .collect(),
+        }
+    }
+}
+
+/// `c x^k`, for a non-zero `c`.
+fn monomial<R: Zero>(c: R, k: usize) -> Unipol<R> {
+    Unipol {
+        coeffs: iter::repeat_with(R::zero)
+            .take(k)
+            .chain(iter::once(c))
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monomial::*;
+    use crate::polynomial::{Ordpol, Polynomial};
+
+    fn poly(cs: &[isize]) -> Unipol<isize> {
+        Unipol {
+            coeffs: cs.to_vec(),
+        }
+        .normalise()
+    }
+
+    fn eval(f: &Unipol<isize>, x: isize) -> isize {
+        f.coeffs.iter().rev().fold(0, |acc, c| acc * x + c)
+    }
+
+    #[test]
+    fn pseudo_division() {
+        let f = poly(&[1, 2, 0, 3]);
+        let g = poly(&[1, 2]);
+        let (q, r) = f.pseudo_div_mod(&g);
+        assert!(r.degree().unwrap_or(0) < 1);
+        assert_eq!(Scalar(8) * f.clone(), q * g.clone() + r);
+        assert_eq!(g.pseudo_div_mod(&f), (Unipol::zero(), g.clone()));
+    }
+
+    #[test]
+    fn knuth_subresultants() {
+        let f = poly(&[-5, 2, 8, -3, -3, 0, 1, 0, 1]);
+        let g = poly(&[21, -9, -4, 0, 5, 0, 3]);
+        let expected = vec![
+            f.clone(),
+            g.clone(),
+            poly(&[9, 0, -3, 0, 15]),
+            poly(&[-245, 125, 65]),
+            poly(&[-12300, 9326]),
+            poly(&[260708]),
+        ];
+        assert_eq!(f.subresultants(&g), expected);
+        assert_eq!(g.subresultants(&f), expected);
+        assert_eq!(f.resultant(&g), 260708);
+    }
+
+    #[test]
+    fn resultant_by_roots() {
+        // 2 (x - 1)(x - 2)(x + 3)
+        let f = poly(&[12, -14, 0, 2]);
+        for g in &[
+            poly(&[1, 1]),
+            poly(&[-7, 0, 3, 1]),
+            poly(&[5]),
+            poly(&[0, 0, 1, -4, 2]),
+        ] {
+            let n = g.degree().unwrap() as u32;
+            let by_roots = 2isize.pow(n) * eval(g, 1) * eval(g, 2) * eval(g, -3);
+            assert_eq!(f.resultant(g), by_roots);
+            let sign = if n.is_even() { 1 } else { -1 };
+            assert_eq!(g.resultant(&f), sign * by_roots);
+        }
+        assert_eq!(f.resultant(&poly(&[-1, 1])), 0);
+        assert_eq!(f.resultant(&Unipol::zero()), 0);
+        assert_eq!(poly(&[3]).resultant(&poly(&[5])), 1);
+    }
+
+    #[test]
+    fn discriminants() {
+        assert_eq!(poly(&[5, 3, 2]).discriminant(), 9 - 4 * 2 * 5);
+        // x^3 + p x + q
+        assert_eq!(poly(&[4, -3, 0, 1]).discriminant(), 4 * 27 - 27 * 16);
+        assert_eq!(poly(&[-1, 0, 1]).discriminant(), 4);
+        assert_eq!(poly(&[1, 2, 1]).discriminant(), 0);
+    }
+
+    #[test]
+    fn eliminate_by_resultant() {
+        type P = Ordpol<isize, Grevlex3>;
+        let x = P::var(grevlex3::X);
+        // y^2 + x^2 - 1 and y - x, as polynomials in y
+        let f = Unipol {
+            coeffs: vec![x.clone() * x.clone() - P::one(), P::zero(), P::one()],
+        };
+        let g = Unipol {
+            coeffs: vec![-x.clone(), P::one()],
+        };
+        assert_eq!(f.resultant(&g), P::from_int(2) * x.clone() * x - P::one());
+    }
+}
@@ -1,7 +1,9 @@
+use num::bigint::{BigInt, ToBigInt};
 use num_integer::*;
 use num_rational::*;
 use num_traits::*;
 use std::iter;
+use std::mem;
 use std::ops::*;
 
 pub trait Semiring: Clone + Eq + Zero + One + AddAssign<Self> + MulAssign<Self> {
@@ -58,7 +60,311 @@ pub trait Field: Ring + Div<Self, Output = Self> {
     }
 }
 
+impl Semiring for BigInt {
+    fn from_nat(i: usize) -> BigInt {
+        BigInt::from(i)
+    }
+}
+
+impl Ring for BigInt {
+    fn from_int(i: isize) -> BigInt {
+        BigInt::from(i)
+    }
+}
+
 impl<I: NumAssign + Ring + Integer> Semiring for Ratio<I> {}
 
 impl<I: NumAssign + Ring + Integer> Ring for Ratio<I> {}
 impl<I: NumAssign + Ring + Integer> Field for Ratio<I> {}
+
+/// Ring without zero divisors, which can tell whether an element divides another.
+pub trait IntegralDomain: Ring {
+    /// Quotient of `self` by `other`, if `other` divides `self`; `None` if dividing by zero.
+    fn exact_div(&self, other: &Self) -> Option<Self>;
+}
+
+/// Integral domain in which any two elements have a greatest common divisor.
+///
+/// Gcds and lcms are only determined up to units;
+/// implementations return the normalised ones (see `EuclideanDomain::unit_part`).
+/// The methods are suffixed so as not to clash with `Integer::gcd` and `Integer::lcm`.
+pub trait GcdDomain: IntegralDomain {
+    fn gcd_domain(&self, other: &Self) -> Self;
+
+    fn lcm_domain(&self, other: &Self) -> Self;
+}
+
+/// Integral domain with division with remainder.
+pub trait EuclideanDomain: GcdDomain {
+    /// Quotient and remainder of `self` by a non-zero `other`,
+    /// the remainder being zero or smaller than `other` in the Euclidean norm;
+    /// for integers, the remainder is non-negative (unlike `Integer::div_rem`).
+    fn div_rem_domain(&self, other: &Self) -> (Self, Self);
+
+    /// Unit `u` such that `self / u` is the normalised associate of `self`; one for zero.
+    fn unit_part(&self) -> Self;
+
+    /// Extended Euclidean algorithm: `(g, s, t)` with `g = gcd(self, other) = s * self + t * other`.
+    fn xgcd(&self, other: &Self) -> (Self, Self, Self) {
+        let (mut r0, mut r1) = (self.clone(), other.clone());
+        let (mut s0, mut s1) = (Self::one(), Self::zero());
+        let (mut t0, mut t1) = (Self::zero(), Self::one());
+        while !r1.is_zero() {
+            let (q, r) = r0.div_rem_domain(&r1);
+            let s = s0 - q.clone() * s1.clone();
+            let t = t0 - q * t1.clone();
+            r0 = mem::replace(&mut r1, r);
+            s0 = mem::replace(&mut s1, s);
+            t0 = mem::replace(&mut t1, t);
+        }
+        let u = r0.unit_part();
+        (
+            r0.div_rem_domain(&u).0,
+            s0.div_rem_domain(&u).0,
+            t0.div_rem_domain(&u).0,
+        )
+    }
+}
+
+/// Gcd by the Euclidean algorithm.
+pub fn euclid_gcd<E: EuclideanDomain>(a: &E, b: &E) -> E {
+    let (mut a, mut b) = (a.clone(), b.clone());
+    while !b.is_zero() {
+        let r = a.div_rem_domain(&b).1;
+        a = mem::replace(&mut b, r);
+    }
+    let u = a.unit_part();
+    a.div_rem_domain(&u).0
+}
+
+/// Lcm computed from the gcd.
+pub fn euclid_lcm<E: EuclideanDomain>(a: &E, b: &E) -> E {
+    if a.is_zero() || b.is_zero() {
+        return E::zero();
+    }
+    let l = a.div_rem_domain(&a.gcd_domain(b)).0 * b.clone();
+    let u = l.unit_part();
+    l.div_rem_domain(&u).0
+}
+
+macro_rules! impl_euclidean_integer {
+    ($($t:ty),*) => {
+        $(
+        impl IntegralDomain for $t {
+            fn exact_div(&self, other: &$t) -> Option<$t> {
+                if other.is_zero() || !self.is_multiple_of(other) {
+                    None
+                } else {
+                    Some(self / other)
+                }
+            }
+        }
+
+        impl GcdDomain for $t {
+            fn gcd_domain(&self, other: &$t) -> $t {
+                self.gcd(other)
+            }
+
+            fn lcm_domain(&self, other: &$t) -> $t {
+                self.lcm(other)
+            }
+        }
+
+        impl EuclideanDomain for $t {
+            /// Division with a non-negative remainder.
+            fn div_rem_domain(&self, other: &$t) -> ($t, $t) {
+                let (q, r) = Integer::div_rem(self, other);
+                if !r.is_negative() {
+                    (q, r)
+                } else if other.is_positive() {
+                    (q - <$t>::one(), r + other)
+                } else {
+                    (q + <$t>::one(), r - other)
+                }
+            }
+
+            fn unit_part(&self) -> $t {
+                if self.is_negative() {
+                    -<$t>::one()
+                } else {
+                    <$t>::one()
+                }
+            }
+        }
+        )*
+    };
+}
+
+impl_euclidean_integer!(isize, BigInt);
+
+macro_rules! impl_euclidean_field {
+    ($([$($g:tt)*] $t:ty),*) => {
+        $(
+        impl<$($g)*> IntegralDomain for $t {
+            fn exact_div(&self, other: &$t) -> Option<$t> {
+                self.clone().try_div(other.clone())
+            }
+        }
+
+        impl<$($g)*> GcdDomain for $t {
+            fn gcd_domain(&self, other: &$t) -> $t {
+                euclid_gcd(self, other)
+            }
+
+            fn lcm_domain(&self, other: &$t) -> $t {
+                euclid_lcm(self, other)
+            }
+        }
+
+        impl<$($g)*> EuclideanDomain for $t {
+            fn div_rem_domain(&self, other: &$t) -> ($t, $t) {
+                (self.clone() / other.clone(), <$t>::zero())
+            }
+
+            fn unit_part(&self) -> $t {
+                if self.is_zero() {
+                    <$t>::one()
+                } else {
+                    self.clone()
+                }
+            }
+        }
+        )*
+    };
+}
+
+impl_euclidean_field!([I: NumAssign + Ring + Integer] Ratio<I>, [M: Modulus] Fp<M>);
+
+/// Integer types to which arbitrary-precision integers can be narrowed, when they fit.
+pub trait FromBigInt: Sized {
+    fn from_bigint(n: &BigInt) -> Option<Self>;
+}
+
+macro_rules! impl_from_bigint {
+    ($($t:ty => $to:ident),*) => {
+        $(
+        impl FromBigInt for $t {
+            fn from_bigint(n: &BigInt) -> Option<$t> {
+                n.$to()
+            }
+        }
+        )*
+    };
+}
+
+impl_from_bigint!(isize => to_isize, i64 => to_i64, i32 => to_i32);
+
+impl FromBigInt for BigInt {
+    fn from_bigint(n: &BigInt) -> Option<BigInt> {
+        Some(n.clone())
+    }
+}
+
+/// Euclidean domains which may be embedded in the rationals,
+/// so that gcds of polynomials over them can be computed modulo primes.
+///
+/// Both methods return `None` by default, for rings such as finite fields.
+pub trait RationalCoeff: EuclideanDomain {
+    /// Numerator and positive denominator, if `self` is a rational number.
+    fn to_rational(&self) -> Option<(BigInt, BigInt)> {
+        None
+    }
+
+    /// The element equal to the integer `n`, if there is one.
+    fn from_integer(_n: &BigInt) -> Option<Self> {
+        None
+    }
+}
+
+macro_rules! impl_rational_coeff_integer {
+    ($([$($g:tt)*] $t:ty),*) => {
+        $(
+        impl<$($g)*> RationalCoeff for $t {
+            fn to_rational(&self) -> Option<(BigInt, BigInt)> {
+                Some((self.to_bigint()?, BigInt::one()))
+            }
+
+            fn from_integer(n: &BigInt) -> Option<$t> {
+                <$t>::from_bigint(n)
+            }
+        }
+        )*
+    };
+}
+
+impl_rational_coeff_integer!(
+    [] isize,
+    [] BigInt,
+    [I: CheckedInt + ToBigInt + FromBigInt] Checked<I>
+);
+
+impl<I> RationalCoeff for Ratio<I>
+where
+    I: NumAssign + Ring + Integer + ToBigInt + FromBigInt,
+{
+    fn to_rational(&self) -> Option<(BigInt, BigInt)> {
+        Some((self.numer().to_bigint()?, self.denom().to_bigint()?))
+    }
+
+    fn from_integer(n: &BigInt) -> Option<Ratio<I>> {
+        I::from_bigint(n).map(Ratio::from_integer)
+    }
+}
+
+impl<M: Modulus> RationalCoeff for Fp<M> {}
+
+pub mod modular;
+pub use self::modular::*;
+
+pub mod checked;
+pub use self::checked::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integer_xgcd() {
+        for &(a, b) in &[(240isize, 46isize), (-12, 18), (7, 0), (0, -5), (0, 0)] {
+            let (g, s, t) = a.xgcd(&b);
+            assert_eq!(g, a.gcd(&b));
+            assert_eq!(s * a + t * b, g);
+            let (g, s, t) = BigInt::from(a).xgcd(&BigInt::from(b));
+            assert_eq!(g, BigInt::from(a.gcd(&b)));
+            assert_eq!(s * a + t * b, g);
+        }
+    }
+
+    #[test]
+    fn euclidean_division() {
+        for &(a, b) in &[(7isize, 2isize), (-7, 2), (7, -2), (-7, -2), (-6, 3)] {
+            let (q, r) = a.div_rem_domain(&b);
+            assert!(0 <= r && r < b.abs());
+            assert_eq!(q * b + r, a);
+            assert_eq!(
+                BigInt::from(a).div_rem_domain(&BigInt::from(b)),
+                (BigInt::from(q), BigInt::from(r))
+            );
+        }
+        assert_eq!((-7isize).div_rem_domain(&-2), (4, 1));
+    }
+
+    #[test]
+    fn exact_division() {
+        assert_eq!(12isize.exact_div(&-4), Some(-3));
+        assert_eq!(12isize.exact_div(&5), None);
+        assert_eq!(BigInt::from(12).exact_div(&BigInt::zero()), None);
+        assert_eq!(
+            Rational::new(1, 2).exact_div(&Rational::new(3, 4)),
+            Some(Rational::new(2, 3))
+        );
+    }
+
+    #[test]
+    fn field_gcd() {
+        let half = Rational::new(1, 2);
+        assert_eq!(half.gcd_domain(&Rational::zero()), Rational::one());
+        assert_eq!(half.lcm_domain(&Rational::new(-3, 4)), Rational::one());
+        assert_eq!(half.xgcd(&Rational::from_integer(3)).0, Rational::one());
+    }
+}
@@ -0,0 +1,414 @@
+use crate::ring::*;
+use num::bigint::{BigInt, ToBigInt};
+use num_integer::Integer;
+use num_rational::Ratio;
+use std::error;
+use std::fmt;
+use std::hash::Hash;
+
+/// Machine-word signed integers usable in `Checked`.
+pub trait CheckedInt:
+    PrimInt + Signed + Integer + CheckedNeg + CheckedRem + Hash + fmt::Debug + fmt::Display
+{
+}
+
+impl<I> CheckedInt for I where
+    I: PrimInt + Signed + Integer + CheckedNeg + CheckedRem + Hash + fmt::Debug + fmt::Display
+{
+}
+
+/// Machine-word integer whose arithmetic detects overflow, instead of wrapping
+/// (as primitive integers silently do in release builds).
+///
+/// The methods of `CheckedArithmetic` report an overflow as an error. The operators,
+/// through which `Checked` is a `Ring`, panic on overflow instead, even in release builds.
+/// `Ratio<Checked<isize>>`, i.e. `CheckedRational`, is thus a field of rationals
+/// which never produces wrong results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Checked<I>(pub I);
+
+/// Rationals over machine words, failing on overflow.
+pub type CheckedRational = num_rational::Ratio<Checked<isize>>;
+
+/// Error reported by the methods of `CheckedArithmetic`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow;
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("arithmetic overflow in checked integer")
+    }
+}
+
+impl error::Error for Overflow {}
+
+/// Panics with the overflow, for the operators which cannot return it.
+fn unwrap_overflow<T>(r: Result<T, Overflow>) -> T {
+    r.unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Arithmetic of `Checked` integers and of rationals over them, reporting overflow as an error.
+/// Division by zero panics, as for primitive integers.
+pub trait CheckedArithmetic: Sized {
+    fn checked_add(&self, other: &Self) -> Result<Self, Overflow>;
+    fn checked_sub(&self, other: &Self) -> Result<Self, Overflow>;
+    fn checked_mul(&self, other: &Self) -> Result<Self, Overflow>;
+    fn checked_div(&self, other: &Self) -> Result<Self, Overflow>;
+    fn checked_neg(&self) -> Result<Self, Overflow>;
+}
+
+impl<I: CheckedInt> Checked<I> {
+    /// Turns the checked result of a primitive operation into a result.
+    #[inline]
+    fn lift(r: Option<I>) -> Result<Checked<I>, Overflow> {
+        r.map(Checked).ok_or(Overflow)
+    }
+
+    pub fn abs(self) -> Checked<I> {
+        if self.0.is_negative() {
+            -self
+        } else {
+            self
+        }
+    }
+
+    fn checked_rem(&self, other: &Checked<I>) -> Result<Checked<I>, Overflow> {
+        assert!(!other.is_zero(), "attempt to divide by zero");
+        Checked::lift(self.0.checked_rem(&other.0))
+    }
+
+    fn checked_gcd(&self, other: &Checked<I>) -> Result<Checked<I>, Overflow> {
+        if self.0 == I::min_value() || other.0 == I::min_value() {
+            return Err(Overflow);
+        }
+        Ok(Checked(self.0.gcd(&other.0)))
+    }
+}
+
+impl<I: CheckedInt> CheckedArithmetic for Checked<I> {
+    fn checked_add(&self, other: &Checked<I>) -> Result<Checked<I>, Overflow> {
+        Checked::lift(self.0.checked_add(&other.0))
+    }
+
+    fn checked_sub(&self, other: &Checked<I>) -> Result<Checked<I>, Overflow> {
+        Checked::lift(self.0.checked_sub(&other.0))
+    }
+
+    fn checked_mul(&self, other: &Checked<I>) -> Result<Checked<I>, Overflow> {
+        Checked::lift(self.0.checked_mul(&other.0))
+    }
+
+    fn checked_div(&self, other: &Checked<I>) -> Result<Checked<I>, Overflow> {
+        assert!(!other.is_zero(), "attempt to divide by zero");
+        Checked::lift(self.0.checked_div(&other.0))
+    }
+
+    fn checked_neg(&self) -> Result<Checked<I>, Overflow> {
+        Checked::lift(self.0.checked_neg())
+    }
+}
+
+impl<I: CheckedInt> CheckedArithmetic for Ratio<Checked<I>> {
+    fn checked_add(&self, other: &Self) -> Result<Self, Overflow> {
+        let (a, b) = (self.numer(), self.denom());
+        let (c, d) = (other.numer(), other.denom());
+        let n = a.checked_mul(d)?.checked_add(&c.checked_mul(b)?)?;
+        normalize(n, b.checked_mul(d)?)
+    }
+
+    fn checked_sub(&self, other: &Self) -> Result<Self, Overflow> {
+        self.checked_add(&other.checked_neg()?)
+    }
+
+    fn checked_mul(&self, other: &Self) -> Result<Self, Overflow> {
+        let n = self.numer().checked_mul(other.numer())?;
+        normalize(n, self.denom().checked_mul(other.denom())?)
+    }
+
+    fn checked_div(&self, other: &Self) -> Result<Self, Overflow> {
+        assert!(!other.is_zero(), "attempt to divide by zero");
+        let n = self.numer().checked_mul(other.denom())?;
+        normalize(n, self.denom().checked_mul(other.numer())?)
+    }
+
+    fn checked_neg(&self) -> Result<Self, Overflow> {
+        Ok(Ratio::new_raw(self.numer().checked_neg()?, *self.denom()))
+    }
+}
+
+/// `n / d` in lowest terms with a positive denominator, for a nonzero `d`.
+fn normalize<I: CheckedInt>(n: Checked<I>, d: Checked<I>) -> Result<Ratio<Checked<I>>, Overflow> {
+    let g = n.checked_gcd(&d)?;
+    let (n, d) = (n.checked_div(&g)?, d.checked_div(&g)?);
+    if d.0.is_negative() {
+        Ok(Ratio::new_raw(n.checked_neg()?, d.checked_neg()?))
+    } else {
+        Ok(Ratio::new_raw(n, d))
+    }
+}
+
+impl<I: fmt::Display> fmt::Display for Checked<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<I: CheckedInt> Add for Checked<I> {
+    type Output = Checked<I>;
+    fn add(self, other: Checked<I>) -> Checked<I> {
+        unwrap_overflow(self.checked_add(&other))
+    }
+}
+
+impl<I: CheckedInt> Sub for Checked<I> {
+    type Output = Checked<I>;
+    fn sub(self, other: Checked<I>) -> Checked<I> {
+        unwrap_overflow(self.checked_sub(&other))
+    }
+}
+
+impl<I: CheckedInt> Mul for Checked<I> {
+    type Output = Checked<I>;
+    fn mul(self, other: Checked<I>) -> Checked<I> {
+        unwrap_overflow(self.checked_mul(&other))
+    }
+}
+
+impl<I: CheckedInt> Div for Checked<I> {
+    type Output = Checked<I>;
+    fn div(self, other: Checked<I>) -> Checked<I> {
+        unwrap_overflow(self.checked_div(&other))
+    }
+}
+
+impl<I: CheckedInt> Rem for Checked<I> {
+    type Output = Checked<I>;
+    fn rem(self, other: Checked<I>) -> Checked<I> {
+        unwrap_overflow(self.checked_rem(&other))
+    }
+}
+
+impl<I: CheckedInt> Neg for Checked<I> {
+    type Output = Checked<I>;
+    fn neg(self) -> Checked<I> {
+        unwrap_overflow(self.checked_neg())
+    }
+}
+
+derive_assign_with!(impl AddAssign, add_assign as Add, add for Checked<I> where I: CheckedInt);
+derive_assign_with!(impl SubAssign, sub_assign as Sub, sub for Checked<I> where I: CheckedInt);
+derive_assign_with!(impl MulAssign, mul_assign as Mul, mul for Checked<I> where I: CheckedInt);
+derive_assign_with!(impl DivAssign, div_assign as Div, div for Checked<I> where I: CheckedInt);
+derive_assign_with!(impl RemAssign, rem_assign as Rem, rem for Checked<I> where I: CheckedInt);
+
+impl<I: CheckedInt> Zero for Checked<I> {
+    fn zero() -> Checked<I> {
+        Checked(I::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl<I: CheckedInt> One for Checked<I> {
+    fn one() -> Checked<I> {
+        Checked(I::one())
+    }
+}
+
+impl<I: CheckedInt> Num for Checked<I> {
+    type FromStrRadixErr = I::FromStrRadixErr;
+    fn from_str_radix(s: &str, radix: u32) -> Result<Checked<I>, I::FromStrRadixErr> {
+        I::from_str_radix(s, radix).map(Checked)
+    }
+}
+
+impl<I: CheckedInt> Integer for Checked<I> {
+    fn div_floor(&self, other: &Checked<I>) -> Checked<I> {
+        // The only overflowing division is `MIN / -1`.
+        unwrap_overflow(
+            self.checked_div(other)
+                .map(|_| Checked(self.0.div_floor(&other.0))),
+        )
+    }
+
+    fn mod_floor(&self, other: &Checked<I>) -> Checked<I> {
+        unwrap_overflow(
+            self.checked_rem(other)
+                .map(|_| Checked(self.0.mod_floor(&other.0))),
+        )
+    }
+
+    fn gcd(&self, other: &Checked<I>) -> Checked<I> {
+        unwrap_overflow(self.checked_gcd(other))
+    }
+
+    fn lcm(&self, other: &Checked<I>) -> Checked<I> {
+        if self.is_zero() && other.is_zero() {
+            return Checked::zero();
+        }
+        let g = self.gcd(other);
+        (*self / g * *other).abs()
+    }
+
+    fn divides(&self, other: &Checked<I>) -> bool {
+        self.is_multiple_of(other)
+    }
+
+    fn is_multiple_of(&self, other: &Checked<I>) -> bool {
+        if other.is_zero() {
+            self.is_zero()
+        } else {
+            // Only `min_value() % -1` overflows, and it is a multiple.
+            self.checked_rem(other).map_or(true, |r| r.is_zero())
+        }
+    }
+
+    fn is_even(&self) -> bool {
+        self.0.is_even()
+    }
+
+    fn is_odd(&self) -> bool {
+        self.0.is_odd()
+    }
+
+    fn div_rem(&self, other: &Checked<I>) -> (Checked<I>, Checked<I>) {
+        (*self / *other, *self % *other)
+    }
+}
+
+impl<I: CheckedInt> Semiring for Checked<I> {
+    fn from_nat(n: usize) -> Checked<I> {
+        unwrap_overflow(Checked::lift(NumCast::from(n)))
+    }
+}
+
+impl<I: CheckedInt> Ring for Checked<I> {
+    fn from_int(n: isize) -> Checked<I> {
+        unwrap_overflow(Checked::lift(NumCast::from(n)))
+    }
+}
+
+impl<I: CheckedInt> IntegralDomain for Checked<I> {
+    fn exact_div(&self, other: &Checked<I>) -> Option<Checked<I>> {
+        if other.is_zero() || !self.is_multiple_of(other) {
+            None
+        } else {
+            self.checked_div(other).ok()
+        }
+    }
+}
+
+impl<I: CheckedInt> GcdDomain for Checked<I> {
+    fn gcd_domain(&self, other: &Checked<I>) -> Checked<I> {
+        self.gcd(other)
+    }
+
+    fn lcm_domain(&self, other: &Checked<I>) -> Checked<I> {
+        self.lcm(other)
+    }
+}
+
+impl<I: CheckedInt> EuclideanDomain for Checked<I> {
+    /// Division with a non-negative remainder.
+    fn div_rem_domain(&self, other: &Checked<I>) -> (Checked<I>, Checked<I>) {
+        let (q, r) = Integer::div_rem(self, other);
+        if !r.0.is_negative() {
+            (q, r)
+        } else if other.0.is_positive() {
+            (q - Checked::one(), r + *other)
+        } else {
+            (q + Checked::one(), r - *other)
+        }
+    }
+
+    fn unit_part(&self) -> Checked<I> {
+        if self.0.is_negative() {
+            -Checked::one()
+        } else {
+            Checked::one()
+        }
+    }
+}
+
+impl<I: ToBigInt> ToBigInt for Checked<I> {
+    fn to_bigint(&self) -> Option<BigInt> {
+        self.0.to_bigint()
+    }
+}
+
+impl<I: FromBigInt> FromBigInt for Checked<I> {
+    fn from_bigint(n: &BigInt) -> Option<Checked<I>> {
+        I::from_bigint(n).map(Checked)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monomial::*;
+    use crate::polynomial::*;
+    use crate::scalar::*;
+    use num_rational::Ratio;
+
+    #[test]
+    fn checked_arithmetic() {
+        let big = Checked(i64::MAX);
+        assert_eq!(
+            big.checked_sub(&Checked(1))
+                .and_then(|n| n.checked_add(&Checked(1))),
+            Ok(big)
+        );
+        assert_eq!(big.checked_add(&Checked(1)), Err(Overflow));
+        assert_eq!(Checked(i64::MIN).checked_neg(), Err(Overflow));
+        assert_eq!(Checked(i64::MIN).checked_div(&-Checked(1)), Err(Overflow));
+        assert_eq!(Checked(-12i64).lcm(&Checked(18)), Checked(36));
+        assert_eq!(
+            Checked(-12i64).xgcd(&Checked(18)),
+            (Checked(6), Checked(1), Checked(1))
+        );
+        assert_eq!(Checked(-7i64).div_floor(&Checked(2)), Checked(-4));
+        assert_eq!(
+            Checked(-7i64).div_rem_domain(&-Checked(2)),
+            (Checked(4), Checked(1))
+        );
+    }
+
+    #[test]
+    fn divisibility_does_not_overflow() {
+        let min = Checked(i64::MIN);
+        assert!(min.is_multiple_of(&Checked(-1)));
+        assert!(min.is_multiple_of(&Checked(2)));
+        assert!(!min.is_multiple_of(&Checked(3)));
+        assert_eq!(min.exact_div(&Checked(-1)), None);
+        assert_eq!(min.exact_div(&Checked(2)), Some(Checked(i64::MIN / 2)));
+    }
+
+    #[test]
+    fn checked_rationals() {
+        let half = CheckedRational::new(Checked(1), Checked(2));
+        let third = CheckedRational::new(Checked(1), Checked(3));
+        assert_eq!(half + third, CheckedRational::new(Checked(5), Checked(6)));
+        assert_eq!((half - third).to_string(), "1/6");
+        assert_eq!(half.checked_sub(&third), Ok(half - third));
+        assert_eq!(half.checked_div(&-third), Ok(half / -third));
+        assert_eq!(third.checked_mul(&half), Ok(third * half));
+
+        let huge = Ratio::new(Checked(isize::MAX), Checked(1));
+        assert_eq!(huge.checked_mul(&huge), Err(Overflow));
+        assert_eq!(huge.checked_add(&huge), Err(Overflow));
+        assert_eq!(huge.checked_div(&half), Err(Overflow));
+        let x: Ordpol<CheckedRational, Grevlex3> = Ordpol::var(grevlex3::X);
+        assert_eq!(x.clone() * x.clone(), x.pow(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "arithmetic overflow")]
+    fn operators_panic_on_overflow() {
+        let huge = Ratio::new(Checked(isize::MAX), Checked(1));
+        let x: Ordpol<CheckedRational, Grevlex3> = Ordpol::var(grevlex3::X);
+        let f = Scalar(huge) * (x + Ordpol::one());
+        let _ = f.clone() * f;
+    }
+}
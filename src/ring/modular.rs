@@ -0,0 +1,386 @@
+use crate::ring::*;
+use std::cell::Cell;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+
+#[cfg(test)]
+use quickcheck::*;
+
+/// Supplies the (prime) modulus of `Fp`.
+pub trait Modulus: 'static {
+    fn modulus() -> u64;
+}
+
+/// Modulus fixed at compile time. `P` must be a prime below `2^63`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ConstModulus<const P: u64>;
+
+impl<const P: u64> ConstModulus<P> {
+    /// Rejects, at compile time, a modulus for which additions would overflow.
+    const BELOW_2_63: () = assert!(P < 1 << 63, "modulus must be below 2^63");
+}
+
+impl<const P: u64> Modulus for ConstModulus<P> {
+    #[inline]
+    fn modulus() -> u64 {
+        let () = Self::BELOW_2_63;
+        P
+    }
+}
+
+thread_local! {
+    static RUNTIME_MODULUS: Cell<u64> = const { Cell::new(0) };
+}
+
+/// Modulus chosen at runtime, valid within the dynamic extent of `RuntimeModulus::with`.
+/// Elements created under one modulus must not be used under another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RuntimeModulus;
+
+impl RuntimeModulus {
+    /// Runs `body` with the thread-local modulus set to the prime `p`, which must be below `2^63`.
+    pub fn with<T, F: FnOnce() -> T>(p: u64, body: F) -> T {
+        assert!(p < 1 << 63, "{} is not below 2^63", p);
+        assert!(is_prime(p), "{} is not a word-sized prime", p);
+        struct Restore(u64);
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                RUNTIME_MODULUS.with(|m| m.set(self.0));
+            }
+        }
+        let _restore = Restore(RUNTIME_MODULUS.with(|m| m.replace(p)));
+        body()
+    }
+}
+
+impl Modulus for RuntimeModulus {
+    #[inline]
+    fn modulus() -> u64 {
+        let p = RUNTIME_MODULUS.with(Cell::get);
+        assert!(
+            p != 0,
+            "RuntimeModulus used outside of RuntimeModulus::with"
+        );
+        p
+    }
+}
+
+/// Prime field Z/pZ, with p given by `M`.
+pub struct Fp<M> {
+    value: u64,
+    modulus: PhantomData<fn() -> M>,
+}
+
+/// Prime field with a compile-time modulus.
+pub type GF<const P: u64> = Fp<ConstModulus<P>>;
+
+impl<M: Modulus> Fp<M> {
+    /// Reduces `n` modulo p.
+    pub fn new(n: u64) -> Fp<M> {
+        Fp::from_reduced(n % M::modulus())
+    }
+
+    pub fn from_i64(n: i64) -> Fp<M> {
+        let p = M::modulus() as i128;
+        Fp::from_reduced((n as i128).rem_euclid(p) as u64)
+    }
+
+    #[inline]
+    fn from_reduced(value: u64) -> Fp<M> {
+        Fp {
+            value,
+            modulus: PhantomData,
+        }
+    }
+
+    /// The canonical representative in `0..p`.
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    /// The representative in `-p/2..=p/2`.
+    pub fn symmetric(&self) -> i64 {
+        let p = M::modulus();
+        if self.value > p / 2 {
+            -((p - self.value) as i64)
+        } else {
+            self.value as i64
+        }
+    }
+
+    /// Multiplicative inverse by the extended Euclidean algorithm.
+    pub fn inverse(&self) -> Option<Fp<M>> {
+        let p = M::modulus() as i128;
+        let (mut r0, mut r1) = (p, self.value as i128);
+        let (mut t0, mut t1) = (0i128, 1i128);
+        while r1 != 0 {
+            let q = r0 / r1;
+            let (r, t) = (r0 - q * r1, t0 - q * t1);
+            r0 = r1;
+            r1 = r;
+            t0 = t1;
+            t1 = t;
+        }
+        if r0 == 1 {
+            Some(Fp::from_reduced(t0.rem_euclid(p) as u64))
+        } else {
+            None
+        }
+    }
+}
+
+/// Deterministic Miller–Rabin test for 64-bit integers.
+#[allow(unknown_lints, clippy::manual_is_multiple_of)]
+pub fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    for &a in WITNESSES.iter() {
+        if n % a == 0 {
+            return n == a;
+        }
+    }
+    let (mut d, mut s) = (n - 1, 0);
+    while d % 2 == 0 {
+        d /= 2;
+        s += 1;
+    }
+    let mul = |a: u64, b: u64| ((a as u128 * b as u128) % n as u128) as u64;
+    let pow = |mut a: u64, mut e: u64| {
+        let mut r = 1;
+        while e > 0 {
+            if e & 1 == 1 {
+                r = mul(r, a);
+            }
+            a = mul(a, a);
+            e >>= 1;
+        }
+        r
+    };
+    WITNESSES.iter().all(|&a| {
+        let mut x = pow(a, d);
+        if x == 1 || x == n - 1 {
+            return true;
+        }
+        for _ in 1..s {
+            x = mul(x, x);
+            if x == n - 1 {
+                return true;
+            }
+        }
+        false
+    })
+}
+
+impl<M> Clone for Fp<M> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<M> Copy for Fp<M> {}
+
+impl<M> PartialEq for Fp<M> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<M> Eq for Fp<M> {}
+
+impl<M> Hash for Fp<M> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state)
+    }
+}
+
+impl<M> fmt::Debug for Fp<M> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl<M> fmt::Display for Fp<M> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl<M: Modulus> Add for Fp<M> {
+    type Output = Fp<M>;
+    fn add(self, other: Fp<M>) -> Fp<M> {
+        let p = M::modulus();
+        let s = self.value + other.value;
+        Fp::from_reduced(if s >= p { s - p } else { s })
+    }
+}
+
+impl<M: Modulus> Sub for Fp<M> {
+    type Output = Fp<M>;
+    fn sub(self, other: Fp<M>) -> Fp<M> {
+        if self.value >= other.value {
+            Fp::from_reduced(self.value - other.value)
+        } else {
+            Fp::from_reduced(M::modulus() - (other.value - self.value))
+        }
+    }
+}
+
+impl<M: Modulus> Neg for Fp<M> {
+    type Output = Fp<M>;
+    fn neg(self) -> Fp<M> {
+        Fp::zero() - self
+    }
+}
+
+impl<M: Modulus> Mul for Fp<M> {
+    type Output = Fp<M>;
+    fn mul(self, other: Fp<M>) -> Fp<M> {
+        let p = M::modulus() as u128;
+        Fp::from_reduced(((self.value as u128 * other.value as u128) % p) as u64)
+    }
+}
+
+impl<M: Modulus> Div for Fp<M> {
+    type Output = Fp<M>;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, other: Fp<M>) -> Fp<M> {
+        self * other.inverse().expect("division by zero in Fp")
+    }
+}
+
+impl<M: Modulus> Zero for Fp<M> {
+    fn zero() -> Fp<M> {
+        Fp::from_reduced(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.value == 0
+    }
+}
+
+impl<M: Modulus> One for Fp<M> {
+    fn one() -> Fp<M> {
+        Fp::from_reduced(1 % M::modulus())
+    }
+}
+
+impl<M: Modulus> Semiring for Fp<M> {
+    fn from_nat(n: usize) -> Fp<M> {
+        Fp::new(n as u64)
+    }
+
+    fn pow(self, mut n: usize) -> Fp<M> {
+        let (mut acc, mut base) = (Fp::one(), self);
+        while n > 0 {
+            if n & 1 == 1 {
+                acc *= base;
+            }
+            base = base * base;
+            n >>= 1;
+        }
+        acc
+    }
+}
+
+impl<M: Modulus> Ring for Fp<M> {
+    fn from_int(n: isize) -> Fp<M> {
+        Fp::from_i64(n as i64)
+    }
+}
+
+impl<M: Modulus> Field for Fp<M> {
+    fn try_div(self, other: Fp<M>) -> Option<Fp<M>> {
+        other.inverse().map(|i| self * i)
+    }
+
+    fn recip(self) -> Fp<M> {
+        self.inverse().expect("division by zero in Fp")
+    }
+}
+
+derive_assign_with!(impl AddAssign, add_assign as Add, add for Fp<M> where M: Modulus);
+derive_assign_with!(impl SubAssign, sub_assign as Sub, sub for Fp<M> where M: Modulus);
+derive_assign_with!(impl MulAssign, mul_assign as Mul, mul for Fp<M> where M: Modulus);
+derive_assign_with!(impl DivAssign, div_assign as Div, div for Fp<M> where M: Modulus);
+
+#[cfg(test)]
+impl<M: Modulus> Arbitrary for Fp<M> {
+    fn arbitrary<G: Gen>(g: &mut G) -> Fp<M> {
+        Fp::new(u64::arbitrary(g))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monomial::*;
+    use crate::polynomial::*;
+
+    type F7 = GF<7>;
+    type Big = GF<9_223_372_036_854_775_783>;
+
+    quickcheck! {
+        fn add_inverse(a: Big) -> bool {
+            (a + -a).is_zero()
+        }
+
+        fn distributive(a: Big, b: Big, c: Big) -> bool {
+            a * (b + c) == a * b + a * c
+        }
+
+        fn mul_inverse(a: Big) -> bool {
+            a.is_zero() || a * a.recip() == Big::one()
+        }
+
+        fn fermat(a: Big) -> bool {
+            a.is_zero() || a.pow(9_223_372_036_854_775_782) == Big::one()
+        }
+    }
+
+    #[test]
+    fn small_field() {
+        assert_eq!(F7::from_int(-1), F7::new(6));
+        assert_eq!(F7::new(3).recip(), F7::new(5));
+        assert_eq!(F7::new(3).symmetric(), 3);
+        assert_eq!(F7::new(4).symmetric(), -3);
+        assert!(is_prime(2) && is_prime(65_537) && !is_prime(65_535));
+        assert_eq!(F7::new(3).exact_div(&F7::new(5)), Some(F7::new(2)));
+        assert_eq!(F7::new(3).exact_div(&F7::zero()), None);
+        assert_eq!(F7::new(3).gcd_domain(&F7::new(4)), F7::one());
+    }
+
+    #[test]
+    fn runtime_modulus() {
+        let (a, b) = RuntimeModulus::with(13, || {
+            let a = Fp::<RuntimeModulus>::new(5).recip();
+            let b = RuntimeModulus::with(11, || Fp::<RuntimeModulus>::new(5).recip());
+            (a.value(), b.value() + Fp::<RuntimeModulus>::new(14).value())
+        });
+        assert_eq!((a, b), (8, 9 + 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "not below 2^63")]
+    fn runtime_modulus_is_below_2_63() {
+        RuntimeModulus::with(18_446_744_073_709_551_557, || ());
+    }
+
+    #[test]
+    fn groebner_over_fp() {
+        let x: &Ordpol<F7, Grevlex2> = &Ordpol::var(grevlex2::X);
+        let y: &Ordpol<F7, Grevlex2> = &Ordpol::var(grevlex2::Y);
+        let f = x * x * y - Ordpol::one();
+        let g = x * x * x - y * y - x;
+        let gb = buchberger(vec![f.clone(), g.clone()]);
+        for i in 0..gb.len() {
+            for j in 0..i {
+                let s = gb[i].clone().spol(gb[j].clone());
+                assert!(s.div_mod_polys(gb.clone()).1.is_zero());
+            }
+        }
+        assert!((f * g).div_mod_polys(gb).1.is_zero());
+    }
+}
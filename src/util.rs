@@ -0,0 +1,63 @@
+//! Small helpers shared across modules.
+
+/// Iterator over the increasing sequences of `k` indices below `n`, in lexicographic order,
+/// generated one at a time.
+pub(crate) struct Combinations {
+    n: usize,
+    next: Option<Vec<usize>>,
+}
+
+/// The `k`-element subsets of `0..n`; see `Combinations`.
+pub(crate) fn combinations(n: usize, k: usize) -> Combinations {
+    Combinations {
+        n,
+        next: if k <= n { Some((0..k).collect()) } else { None },
+    }
+}
+
+impl Iterator for Combinations {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Vec<usize>> {
+        let current = self.next.take()?;
+        let k = current.len();
+        if let Some(i) = (0..k).rev().find(|i| current[*i] < self.n - k + i) {
+            let mut next = current.clone();
+            next[i] += 1;
+            for j in i + 1..k {
+                next[j] = next[j - 1] + 1;
+            }
+            self.next = Some(next);
+        }
+        Some(current)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn combinations_in_order() {
+        let all: Vec<Vec<usize>> = combinations(4, 2).collect();
+        assert_eq!(
+            all,
+            vec![
+                vec![0, 1],
+                vec![0, 2],
+                vec![0, 3],
+                vec![1, 2],
+                vec![1, 3],
+                vec![2, 3]
+            ]
+        );
+        assert_eq!(combinations(3, 0).collect::<Vec<_>>(), vec![vec![]]);
+        assert_eq!(combinations(3, 3).count(), 1);
+        assert_eq!(combinations(2, 3).count(), 0);
+        assert_eq!(combinations(20, 10).count(), 184_756);
+        assert_eq!(
+            combinations(60, 30).nth(5),
+            Some((0..29).chain(34..35).collect())
+        );
+    }
+}